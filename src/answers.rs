@@ -0,0 +1,156 @@
+//! Known-correct answers, used to check that solutions still produce them.
+//!
+//! Answers for day N live in `answers/<year>/dN`, one section per part:
+//!
+//! ```text
+//! part1: 13140
+//! part2:
+//! ##..##..##..##..##..##..##..##..##..##..
+//! ###...###...###...###...###...###...###.
+//! ```
+//!
+//! A section runs until the next `partN:` line, so multi-line answers can be written as they're
+//! printed.  Leading and trailing whitespace is ignored, both in the file and in the answers being
+//! checked.  Either part can be left out if its answer isn't known yet.
+
+use crate::paths;
+use std::{fmt::Display, fs::read_to_string, path::Path};
+
+/// The recorded answers for one day.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// The result of comparing an answer to the recorded one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// There's no recorded answer to compare against.
+    Unknown,
+}
+
+impl Answers {
+    /// Read the answers from a file.  A missing file means no answers are known.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        read_to_string(path)
+            .map(|text| Self::parse(&text))
+            .unwrap_or_default()
+    }
+
+    /// Read the recorded answers for a day's puzzle input.
+    pub fn for_day(year: u16, day: u8) -> Self {
+        Self::load(paths::answers(year, day))
+    }
+
+    pub fn parse(text: &str) -> Self {
+        let mut sections: [Option<String>; 2] = [None, None];
+        let mut current = None;
+
+        for line in text.lines() {
+            let header = ["part1:", "part2:"]
+                .iter()
+                .enumerate()
+                .find_map(|(i, header)| line.strip_prefix(header).map(|rest| (i, rest)));
+
+            if let Some((i, rest)) = header {
+                current = Some(i);
+                sections[i] = Some(rest.to_string());
+            } else if let Some(section) = current.and_then(|i| sections[i].as_mut()) {
+                section.push('\n');
+                section.push_str(line);
+            }
+        }
+
+        let [part1, part2] = sections.map(|section| {
+            section
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        });
+
+        Self { part1, part2 }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Compare an answer for the given part to the recorded one.
+    pub fn check(&self, part: u8, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer.trim() => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "fail",
+            Verdict::Unknown => "unknown",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::YEARS;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse("part1: 13140\npart2:\n##..\n.##.\n");
+        assert_eq!(answers.part1.as_deref(), Some("13140"));
+        assert_eq!(answers.part2.as_deref(), Some("##..\n.##."));
+
+        let answers = Answers::parse("part2: merry christmas!\n");
+        assert_eq!(answers.part1, None);
+        assert_eq!(answers.get(2), Some("merry christmas!"));
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers::parse("part1: 157\npart2:\n\n##..\n");
+        assert_eq!(answers.check(1, "157"), Verdict::Pass);
+        assert_eq!(answers.check(1, "158"), Verdict::Fail);
+        assert_eq!(answers.check(2, "\n##..\n"), Verdict::Pass);
+        assert_eq!(Answers::default().check(1, "157"), Verdict::Unknown);
+    }
+
+    /// Check every day that has both an input file and recorded answers.  Some days take a long
+    /// time in debug builds, so this is ignored by default; run it with `cargo test -r -- --ignored`
+    /// (or use the runner's `--check` mode).
+    #[test]
+    #[ignore]
+    fn recorded_answers() {
+        for year in YEARS {
+            for solution in year.solutions {
+                let day = solution.day();
+                let Ok(input) = read_to_string(paths::input(year.year, day)) else {
+                    continue;
+                };
+                let answers = Answers::for_day(year.year, day);
+                for part in [1, 2] {
+                    if let Some(expected) = answers.get(part) {
+                        let answer = solution.run(part, &input).unwrap().answer;
+                        assert_eq!(
+                            answer.trim(),
+                            expected,
+                            "{} day {day} part {part}",
+                            year.year
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,33 +1,240 @@
+use crate::config;
+use aoc2022::vis::image::ImageFormat;
+use std::str::FromStr;
+
 pub const HELP: &str = "\
-Usage: aoc2021 -d <day> [-p <part>] [-e] [-i <input>]
+Usage: aoc2022 -d <day> [-y <year>] [-p <part>] [-e [all]] [-i <input>] [--input-str <text>]
+               [-t] [-c] [-f <format>] [-j <jobs>] [--watch | --watch-tests]
+               [--record <file> | --frames <dir> [--frames-format <png|gif>]] [--svg <file>]
+       aoc2022 bench -d <day> [-y <year>] [-p <part>] [-n <iterations>] [-w <warmup>] [--save]
+       aoc2022 new <day> [-y <year>] [--force]
+       aoc2022 fetch -d <day> [-y <year>] [--url <url>] [--cache <dir>]
+       aoc2022 submit -d <day> -p <part> [-y <year>] [--answer <answer>] [--url <url>]
+       aoc2022 repl [-y <year>]
 
 The CLI arguments allowed.
 
+Commands:
+  bench             run a day's part repeatedly and compare against a saved baseline
+  new               create src/yYYYY/dN.rs from templates/d.rs and an empty
+                    examples/YYYY/dN, and register the day in src/yYYYY.rs (existing files
+                    are left alone)
+  fetch             download a day's input into input/YYYY/dN, unless it's there already
+                    (requires AOC_SESSION)
+  submit            send a day's answer to the site, unless the ledger shows it's
+                    wrong (requires AOC_SESSION)
+  repl              read commands like `run 16 2` or `all` until EOF, keeping inputs cached
+                    between them (type `help` for the list)
+
 Options:
   -d, --day         specifies the day
+  -y, --year        specifies the year (default AOC_YEAR, or 2022); every command takes this
   -p, --part        specifies the part
-  -e, --example     use the day's example input from examples/
-  -i, --input       specify an alternate input file
+  -e, --example     use the day's example input from examples/YYYY/ (`-e all` runs every
+                    dN and dN-* file there, checking any recorded answers)
+  -i, --input       specify an alternate input file (`-i -` reads from stdin)
+  --input-str       use the given text as the input
+  -t, --time        print a table of parse and solve times
+  -c, --check       compare answers to the ones recorded in answers/YYYY/ (or, for other
+                    input files, in a sidecar file with an .answers extension)
+  -f, --format      output format: text (default), json (one object per line), or tsv
+  -j, --jobs        how many parts to run at once (default 1, 0 for one per CPU)
+  --watch           re-run whenever the day's source, input or examples change
+  --watch-tests     re-run the day's tests whenever its source, input or examples change
+  --record          write the day's animation (days 5, 17, 22, 23 and 24) to an asciicast
+                    file instead of the terminal
+  --frames          write the day's animation into a directory as images instead
+  --frames-format   png (default, one file per frame) or gif (one animated file)
+  --svg             save a picture of the day's final state (days 9, 12, 14 and 18) as an
+                    SVG file
   -h, --help        display usage information
+
+Bench options:
+  -n, --iterations  number of measured runs (default 20)
+  -w, --warmup      number of unmeasured runs before measuring (default 3)
+  --baseline        baseline file to compare against (default bench.json)
+  --save            write the results into the baseline file
+  --threshold       percent slowdown of the median reported as a regression (default 10)
+
+New options:
+  --force           overwrite the day's source file if it already exists
+
+Fetch options:
+  --url             the site's base URL (default AOC_URL, or https://adventofcode.com)
+  --cache           where downloaded inputs are cached (default ~/.cache/aoc)
+
+Submit options:
+  --answer          the answer to send (default: run the part on input/YYYY/dN)
+  --url             the site's base URL (default AOC_URL, or https://adventofcode.com)
+  --ledger          file recording every submitted answer (default ledger.json)
 ";
 
 pub const INPUT_CONFLICT: &str = "\
-Error: -i/--input and -e/--example can't be used together.
+Error: only one of -i/--input, --input-str and -e/--example can be used.
 ";
 
+/// The modes the binary can run in.
+pub enum Command {
+    /// Run a day (or all days) and print the answers.
+    Run(Args),
+    /// Benchmark a single day's part.
+    Bench(BenchArgs),
+    /// Scaffold a new day.
+    New(NewArgs),
+    /// Download a day's input.
+    Fetch(FetchArgs),
+    /// Submit a day's answer.
+    Submit(SubmitArgs),
+    /// Run commands interactively, in the given year.
+    Repl(u16),
+}
+
 /// The CLI arguments allowed.
 pub struct Args {
     /// specifies the day (255 runs all parts)
     pub day: u8,
+    /// specifies the year
+    pub year: u16,
     /// specifies the part
     pub part: u8,
     /// use the day's example input from examples/
     pub example: bool,
-    /// specify an alternate input file
+    /// run every one of the day's examples (`-e all`)
+    pub all_examples: bool,
+    /// specify an alternate input file ("-" for stdin)
     pub input: Option<String>,
+    /// use the given text as the input
+    pub input_str: Option<String>,
+    /// print a table of parse and solve times
+    pub time: bool,
+    /// compare answers to the ones recorded in answers/
+    pub check: bool,
+    /// output format
+    pub format: Format,
+    /// how many parts to run at once
+    pub jobs: usize,
+    /// re-run whenever the day's files change
+    pub watch: bool,
+    /// re-run the day's tests whenever its files change
+    pub watch_tests: bool,
+    /// write animations to this asciicast file
+    pub record: Option<String>,
+    /// write animations into this directory as images
+    pub frames: Option<String>,
+    /// what kind of images --frames writes
+    pub frames_format: ImageFormat,
+    /// save pictures of the final state to this SVG file
+    pub svg: Option<String>,
+}
+
+impl Args {
+    /// Run part 1 of a day on its input, like `-d <day>` with no other options.
+    pub fn new(day: u8, year: u16) -> Self {
+        Args {
+            day,
+            year,
+            part: 1,
+            example: false,
+            all_examples: false,
+            input: None,
+            input_str: None,
+            time: false,
+            check: false,
+            format: Format::Text,
+            jobs: 1,
+            watch: false,
+            watch_tests: false,
+            record: None,
+            frames: None,
+            frames_format: ImageFormat::Png,
+            svg: None,
+        }
+    }
+}
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Bare answers (prefixed with the day and part when running all days).
+    Text,
+    /// One JSON object per line, per day and part.
+    Json,
+    /// A header row, then one tab-separated row per day and part.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("unknown format {s}")),
+        }
+    }
+}
+
+/// The CLI arguments allowed by the bench command.
+pub struct BenchArgs {
+    /// specifies the day
+    pub day: u8,
+    /// specifies the year
+    pub year: u16,
+    /// specifies the part
+    pub part: u8,
+    /// number of measured runs
+    pub iterations: usize,
+    /// number of unmeasured runs before measuring
+    pub warmup: usize,
+    /// baseline file to compare against
+    pub baseline: String,
+    /// write the results into the baseline file
+    pub save: bool,
+    /// percent slowdown of the median reported as a regression
+    pub threshold: f64,
 }
 
-pub fn parse_args() -> Result<Args, pico_args::Error> {
+/// The CLI arguments allowed by the new command.
+pub struct NewArgs {
+    /// the day to create
+    pub day: u8,
+    /// the year to create it in
+    pub year: u16,
+    /// overwrite the day's source file
+    pub force: bool,
+}
+
+/// The CLI arguments allowed by the fetch command.
+pub struct FetchArgs {
+    /// the day to download
+    pub day: u8,
+    /// the puzzle year
+    pub year: u16,
+    /// the site's base URL
+    pub url: Option<String>,
+    /// where downloaded inputs are cached
+    pub cache: Option<String>,
+}
+
+/// The CLI arguments allowed by the submit command.
+pub struct SubmitArgs {
+    /// specifies the day
+    pub day: u8,
+    /// specifies the part
+    pub part: u8,
+    /// the answer to send, instead of running the part
+    pub answer: Option<String>,
+    /// the puzzle year
+    pub year: u16,
+    /// the site's base URL
+    pub url: Option<String>,
+    /// file recording every submitted answer
+    pub ledger: String,
+}
+
+pub fn parse_args() -> Result<Command, pico_args::Error> {
     let mut pargs = pico_args::Arguments::from_env();
 
     if pargs.contains(["-h", "--help"]) {
@@ -35,17 +242,102 @@ pub fn parse_args() -> Result<Args, pico_args::Error> {
         std::process::exit(0);
     }
 
-    let args = Args {
+    match pargs.subcommand()?.as_deref() {
+        None => {}
+        Some("bench") => return parse_bench_args(pargs).map(Command::Bench),
+        Some("new") => {
+            return Ok(Command::New(NewArgs {
+                force: pargs.contains("--force"),
+                year: year(&mut pargs)?,
+                day: pargs.free_from_str()?,
+            }))
+        }
+        Some("fetch") => {
+            return Ok(Command::Fetch(FetchArgs {
+                day: pargs.value_from_str(["-d", "--day"])?,
+                year: year(&mut pargs)?,
+                url: pargs.opt_value_from_str("--url")?,
+                cache: pargs.opt_value_from_str("--cache")?,
+            }))
+        }
+        Some("submit") => {
+            return Ok(Command::Submit(SubmitArgs {
+                day: pargs.value_from_str(["-d", "--day"])?,
+                part: pargs.value_from_str(["-p", "--part"])?,
+                answer: pargs.opt_value_from_str("--answer")?,
+                year: year(&mut pargs)?,
+                url: pargs.opt_value_from_str("--url")?,
+                ledger: pargs
+                    .value_from_str("--ledger")
+                    .or(Ok("ledger.json".to_string()))?,
+            }))
+        }
+        Some("repl") => return Ok(Command::Repl(year(&mut pargs)?)),
+        Some(cmd) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unknown command {cmd}"),
+            })
+        }
+    }
+
+    let mut args = Args {
         day: pargs.value_from_str(["-d", "--day"])?,
+        year: year(&mut pargs)?,
         part: pargs.value_from_str(["-p", "--part"]).or(Ok(1))?,
         example: pargs.contains(["-e", "--example"]),
         input: pargs.opt_value_from_str(["-i", "--input"])?,
+        input_str: pargs.opt_value_from_str("--input-str")?,
+        time: pargs.contains(["-t", "--time"]),
+        check: pargs.contains(["-c", "--check"]),
+        format: pargs
+            .opt_value_from_str(["-f", "--format"])?
+            .unwrap_or(Format::Text),
+        jobs: pargs.value_from_str(["-j", "--jobs"]).or(Ok(1))?,
+        watch: pargs.contains("--watch"),
+        watch_tests: pargs.contains("--watch-tests"),
+        record: pargs.opt_value_from_str("--record")?,
+        frames: pargs.opt_value_from_str("--frames")?,
+        frames_format: pargs
+            .opt_value_from_str("--frames-format")?
+            .unwrap_or(ImageFormat::Png),
+        svg: pargs.opt_value_from_str("--svg")?,
+        all_examples: false,
     };
 
-    if pargs.contains(["-e", "--example"]) && pargs.contains(["-i", "--input"]) {
+    if args.jobs == 0 {
+        args.jobs = std::thread::available_parallelism().map_or(1, |n| n.get());
+    }
+
+    // `-e all` leaves "all" behind as a free argument
+    args.all_examples = args.example && pargs.finish().iter().any(|arg| arg == "all");
+
+    let inputs = [args.example, args.input.is_some(), args.input_str.is_some()];
+    if inputs.iter().filter(|&&given| given).count() > 1 {
         print!("{INPUT_CONFLICT}");
         std::process::exit(1);
     }
 
-    Ok(args)
+    Ok(Command::Run(args))
+}
+
+fn parse_bench_args(mut pargs: pico_args::Arguments) -> Result<BenchArgs, pico_args::Error> {
+    Ok(BenchArgs {
+        day: pargs.value_from_str(["-d", "--day"])?,
+        year: year(&mut pargs)?,
+        part: pargs.value_from_str(["-p", "--part"]).or(Ok(1))?,
+        iterations: pargs.value_from_str(["-n", "--iterations"]).or(Ok(20))?,
+        warmup: pargs.value_from_str(["-w", "--warmup"]).or(Ok(3))?,
+        baseline: pargs
+            .value_from_str("--baseline")
+            .or(Ok("bench.json".to_string()))?,
+        save: pargs.contains("--save"),
+        threshold: pargs.value_from_str("--threshold").or(Ok(10.0))?,
+    })
+}
+
+/// The `-y/--year` argument, defaulting to `AOC_YEAR`.
+fn year(pargs: &mut pico_args::Arguments) -> Result<u16, pico_args::Error> {
+    Ok(pargs
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(config::year))
 }
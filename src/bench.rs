@@ -0,0 +1,192 @@
+use crate::args::BenchArgs;
+use aoc2022::paths;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io::ErrorKind, time::Duration};
+
+/// Summary statistics for one benchmarked day/part, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: u64,
+    pub median: u64,
+    pub p95: u64,
+    pub max: u64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let nanos = |d: &Duration| d.as_nanos() as u64;
+        Self {
+            iterations: samples.len(),
+            min: nanos(&samples[0]),
+            median: nanos(&samples[percentile_idx(samples.len(), 50.0)]),
+            p95: nanos(&samples[percentile_idx(samples.len(), 95.0)]),
+            max: nanos(&samples[samples.len() - 1]),
+        }
+    }
+}
+
+/// Baseline results, keyed by year, day and part (e.g. "2022/d16p1").
+type Baseline = BTreeMap<String, Stats>;
+
+/// The index of the given percentile in a sorted list of `len` samples (nearest-rank method).
+fn percentile_idx(len: usize, percentile: f64) -> usize {
+    let rank = (percentile / 100.0 * len as f64).ceil() as usize;
+    rank.clamp(1, len) - 1
+}
+
+/// How much slower (positive) or faster (negative) `current` is than `baseline`, in percent.
+/// There's no telling when the baseline took no time at all.
+fn change(baseline: u64, current: u64) -> Option<f64> {
+    (baseline != 0).then(|| 100.0 * (current as f64 - baseline as f64) / baseline as f64)
+}
+
+/// Read the baseline file.  A missing file is an empty baseline, but one that can't be read or
+/// parsed is an error, so saving doesn't overwrite whatever was in it.
+fn load_baseline(path: &str) -> Result<Baseline, String> {
+    match fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| format!("couldn't read {path}: {e}")),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Baseline::default()),
+        Err(e) => Err(format!("couldn't read {path}: {e}")),
+    }
+}
+
+/// Benchmark a day's part and compare it to the baseline file.  Returns false if the median
+/// regressed by more than the threshold.
+pub fn bench(args: BenchArgs) -> bool {
+    let Some(solution) = aoc2022::solution(args.year, args.day) else {
+        eprintln!(
+            "Error: there's no solution for {} day {}",
+            args.year, args.day
+        );
+        return false;
+    };
+    if ![1, 2].contains(&args.part) || args.iterations == 0 {
+        eprintln!("Error: part must be 1 or 2, and iterations must be at least 1");
+        return false;
+    }
+
+    let mut baseline = match load_baseline(&args.baseline) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return false;
+        }
+    };
+
+    let Ok(input) = fs::read_to_string(paths::input(args.year, args.day)) else {
+        eprintln!(
+            "Error: input file for day {} is missing or unreadable",
+            args.day
+        );
+        return false;
+    };
+
+    let samples = (0..args.warmup + args.iterations)
+        .map(|_| solution.run(args.part, &input).map(|run| run.total_time()))
+        .collect::<Result<Vec<_>, _>>();
+    let samples = match samples {
+        Ok(samples) => samples.into_iter().skip(args.warmup).collect(),
+        Err(e) => {
+            eprintln!("Error: day {}: {e}", args.day);
+            return false;
+        }
+    };
+    let stats = Stats::new(samples);
+
+    let key = format!("{}/d{}p{}", args.year, args.day, args.part);
+
+    println!(
+        "day {} part {}: {} iterations ({} warm-up)",
+        args.day, args.part, stats.iterations, args.warmup
+    );
+    let nanos = Duration::from_nanos;
+    println!("  min    {:>12.2?}", nanos(stats.min));
+    println!("  median {:>12.2?}", nanos(stats.median));
+    println!("  p95    {:>12.2?}", nanos(stats.p95));
+    println!("  max    {:>12.2?}", nanos(stats.max));
+
+    let mut ok = true;
+    match baseline.get(&key) {
+        Some(old) => match change(old.median, stats.median) {
+            Some(change) => {
+                let verdict = if change > args.threshold {
+                    ok = false;
+                    "REGRESSION"
+                } else {
+                    "ok"
+                };
+                println!(
+                    "  baseline median {:.2?}: {change:+.1}% {verdict}",
+                    nanos(old.median)
+                );
+            }
+            None => println!("  baseline median {:.2?}: n/a", nanos(old.median)),
+        },
+        None => println!("  no baseline for {key} in {}", args.baseline),
+    }
+
+    if args.save {
+        baseline.insert(key, stats);
+        let json = serde_json::to_string_pretty(&baseline).expect("couldn't serialize baseline");
+        match fs::write(&args.baseline, json) {
+            Ok(_) => println!("  saved to {}", args.baseline),
+            Err(e) => eprintln!("Error: couldn't write {}: {e}", args.baseline),
+        }
+    }
+
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles() {
+        assert_eq!(percentile_idx(1, 50.0), 0);
+        assert_eq!(percentile_idx(1, 95.0), 0);
+        assert_eq!(percentile_idx(10, 50.0), 4);
+        assert_eq!(percentile_idx(10, 95.0), 9);
+        assert_eq!(percentile_idx(100, 95.0), 94);
+    }
+
+    #[test]
+    fn stats() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_nanos).to_vec();
+        assert_eq!(
+            Stats::new(samples),
+            Stats {
+                iterations: 5,
+                min: 1,
+                median: 3,
+                p95: 5,
+                max: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn regression() {
+        assert_eq!(change(100, 110), Some(10.0));
+        assert_eq!(change(100, 50), Some(-50.0));
+        assert_eq!(change(0, 50), None);
+    }
+
+    #[test]
+    fn baseline_files() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-bench-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+
+        assert_eq!(
+            load_baseline(&path("missing.json")),
+            Ok(Baseline::default())
+        );
+        fs::write(path("corrupt.json"), "{\"2022/d1p1\": ").unwrap();
+        assert!(load_baseline(&path("corrupt.json")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
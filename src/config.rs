@@ -0,0 +1,21 @@
+//! Settings that come from the environment, or from the `.env` file that `just` loads.
+
+use std::fs;
+
+/// Look up a setting in the environment, falling back to `.env`.
+pub fn var(name: &str) -> Option<String> {
+    std::env::var(name).ok().or_else(|| {
+        let prefix = format!("{name}=");
+        fs::read_to_string(".env")
+            .ok()?
+            .lines()
+            .find_map(|line| line.strip_prefix(&prefix).map(|v| v.trim().to_string()))
+    })
+}
+
+/// The puzzle year, from `AOC_YEAR`.
+pub fn year() -> u16 {
+    var("AOC_YEAR")
+        .and_then(|year| year.parse().ok())
+        .unwrap_or(aoc2022::DEFAULT_YEAR)
+}
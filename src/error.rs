@@ -0,0 +1,98 @@
+//! The error type shared by every day, so bad input can be reported instead of panicking.
+
+use std::fmt::Display;
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input couldn't be parsed.  Line and column are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        msg: String,
+    },
+    /// The input was parsed but the puzzle has no answer for it (e.g. d6's signal has no marker).
+    NoSolution(String),
+}
+
+impl AocError {
+    /// A parse error at the start of `rest`, which must be a suffix of `input` (as nom leaves it).
+    pub fn parse_at(input: &str, rest: &str, msg: impl Into<String>) -> Self {
+        let offset = input.len().saturating_sub(rest.len());
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        Self::Parse {
+            line,
+            column,
+            msg: msg.into(),
+        }
+    }
+
+    /// A parse error at the start of the given line, for inputs that are parsed line by line.
+    /// `line_idx` is 0-based, as it comes from `enumerate`.
+    pub fn parse_line(line_idx: usize, msg: impl Into<String>) -> Self {
+        Self::Parse {
+            line: line_idx + 1,
+            column: 1,
+            msg: msg.into(),
+        }
+    }
+
+    /// Turn a nom error into a parse error located within `input`.  When a list of lines stops
+    /// parsing, nom is left at the line break before the bad line, so that's skipped over.
+    pub fn from_nom(
+        input: &str,
+        msg: impl Into<String>,
+    ) -> impl FnOnce(nom::Err<nom::error::Error<&str>>) -> Self + '_ {
+        let msg = msg.into();
+        move |err| match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let rest = e.input.strip_prefix('\n').unwrap_or(e.input);
+                Self::parse_at(input, rest, msg)
+            }
+            nom::Err::Incomplete(_) => Self::parse_at(input, "", msg),
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse { line, column, msg } => {
+                write!(f, "parse error at line {line}, column {column}: {msg}")
+            }
+            AocError::NoSolution(msg) => write!(f, "no solution: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_location() {
+        let input = "$ ls\ndir a\n$ cf b\n";
+        let rest = &input[11..];
+        assert_eq!(
+            AocError::parse_at(input, rest, "expected `$ cd`").to_string(),
+            "parse error at line 3, column 1: expected `$ cd`"
+        );
+        assert_eq!(
+            AocError::parse_at(input, &input[13..], "oops"),
+            AocError::Parse {
+                line: 3,
+                column: 3,
+                msg: "oops".into()
+            }
+        );
+        assert_eq!(
+            AocError::parse_line(0, "oops"),
+            AocError::parse_at(input, input, "oops")
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Discovery of each day's example inputs.
+//!
+//! A day's examples are the files in `examples/<year>/` named `dN`, or `dN-` followed by anything
+//! (e.g. `examples/2022/d12-2`).  An example's expected answers can be recorded next to it in a
+//! sidecar file with an `.answers` extension (e.g. `examples/2022/d12-2.answers`), using the same
+//! format as the files in `answers/`.
+
+use crate::{answers::Answers, paths};
+use std::{
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
+
+/// The extension of the sidecar files holding an example's expected answers.
+pub const ANSWERS_EXT: &str = "answers";
+
+/// An example input, and its expected answers (if any were recorded).
+#[derive(Debug)]
+pub struct Example {
+    pub path: PathBuf,
+    pub answers: Answers,
+}
+
+/// Find all of a day's examples in `./examples/<year>`.
+pub fn for_day(year: u16, day: u8) -> Vec<Example> {
+    find(paths::examples(year), day)
+}
+
+/// Find all of a day's examples in the given directory.  The default example (`dN`) comes first,
+/// followed by the rest in name order.
+pub fn find(dir: impl AsRef<Path>, day: u8) -> Vec<Example> {
+    let name = format!("d{day}");
+    let prefix = format!("d{day}-");
+
+    let Ok(entries) = read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                return false;
+            };
+            let is_sidecar = path.extension().is_some_and(|ext| ext == ANSWERS_EXT);
+            path.is_file() && !is_sidecar && (file_name == name || file_name.starts_with(&prefix))
+        })
+        .collect();
+
+    paths.sort_by_key(|path| (path.file_name() != Some(name.as_ref()), path.clone()));
+
+    paths
+        .into_iter()
+        .map(|path| Example {
+            answers: Answers::load(sidecar(&path)),
+            path,
+        })
+        .collect()
+}
+
+/// The path of the sidecar file holding the expected answers for the given input file.
+pub fn sidecar(input: impl AsRef<Path>) -> PathBuf {
+    let mut path = input.as_ref().as_os_str().to_owned();
+    path.push(".");
+    path.push(ANSWERS_EXT);
+    path.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::Verdict, YEARS};
+    use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+
+    #[test]
+    fn find_examples() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-examples-{}", std::process::id()));
+        create_dir_all(&dir).unwrap();
+        for file in [
+            "d1",
+            "d12",
+            "d12-3",
+            "d12-2",
+            "d12-2.answers",
+            "d1-big",
+            "d123",
+        ] {
+            write(dir.join(file), "part1: 1").unwrap();
+        }
+
+        let names = |day| {
+            find(&dir, day)
+                .iter()
+                .map(|ex| ex.path.file_name().unwrap().to_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(12), ["d12", "d12-2", "d12-3"]);
+        assert_eq!(names(1), ["d1", "d1-big"]);
+        assert!(names(2).is_empty());
+
+        let examples = find(&dir, 12);
+        assert_eq!(examples[1].answers.get(1), Some("1"));
+        assert_eq!(examples[0].answers.get(1), None);
+
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn sidecar_path() {
+        assert_eq!(
+            sidecar("examples/d12-2"),
+            PathBuf::from("examples/d12-2.answers")
+        );
+    }
+
+    /// Run every day against each of its examples that has recorded answers.
+    #[test]
+    fn example_answers() {
+        for year in YEARS {
+            for solution in year.solutions {
+                for example in for_day(year.year, solution.day()) {
+                    let input = read_to_string(&example.path).unwrap();
+                    for part in [1, 2] {
+                        if example.answers.get(part).is_none() {
+                            continue;
+                        }
+                        let answer = solution.run(part, &input).unwrap().answer;
+                        assert_eq!(
+                            example.answers.check(part, &answer),
+                            Verdict::Pass,
+                            "{} part {part}: got {answer}, expected {}",
+                            example.path.display(),
+                            example.answers.get(part).unwrap()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
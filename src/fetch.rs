@@ -0,0 +1,255 @@
+//! `aoc fetch`: download a day's puzzle input into `input/<year>/dN`.
+//!
+//! Downloads are cached by year and day (in `~/.cache/aoc` by default), so a deleted or
+//! regenerated `input/` directory doesn't cost another request.  The site's base URL comes from
+//! `--url` or `AOC_URL`, so a local stand-in server can be used for testing.
+
+use crate::{args::FetchArgs, config};
+use aoc2022::paths;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Identifies these tools to the site in every request.
+pub const USER_AGENT: &str = "github.com/mwcz/advent-of-code-2022";
+
+/// Where an input came from.
+#[derive(Debug, PartialEq, Eq)]
+enum Fetched {
+    /// The input file already existed.
+    Exists,
+    Cache,
+    Download,
+}
+
+/// Everything needed to fetch one day's input.
+struct Request<'a> {
+    year: u16,
+    day: u8,
+    url: &'a str,
+    session: Option<&'a str>,
+    input: &'a Path,
+    cache: &'a Path,
+}
+
+pub fn fetch(args: FetchArgs) -> bool {
+    if !(1..=25).contains(&args.day) {
+        eprintln!("Error: DAY must be 1 through 25");
+        return false;
+    }
+
+    let url = args
+        .url
+        .or_else(|| config::var("AOC_URL"))
+        .unwrap_or_else(|| DEFAULT_URL.to_string());
+    let session = config::var("AOC_SESSION");
+    let cache = args.cache.map(PathBuf::from).unwrap_or_else(default_cache);
+    let input = PathBuf::from(paths::input(args.year, args.day));
+
+    let request = Request {
+        year: args.year,
+        day: args.day,
+        url: url.trim_end_matches('/'),
+        session: session.as_deref(),
+        input: &input,
+        cache: &cache,
+    };
+
+    match fetch_input(&request) {
+        Ok(Fetched::Exists) => {
+            println!(
+                "input for day {} already exists in {}",
+                args.day,
+                input.display()
+            );
+            true
+        }
+        Ok(Fetched::Cache) => {
+            println!("input saved from cache: {}", input.display());
+            true
+        }
+        Ok(Fetched::Download) => {
+            println!("input saved: {}", input.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("Error: couldn't fetch day {}'s input: {e}", args.day);
+            false
+        }
+    }
+}
+
+fn fetch_input(req: &Request) -> Result<Fetched, String> {
+    if req.input.exists() {
+        return Ok(Fetched::Exists);
+    }
+
+    let cached = req
+        .cache
+        .join(req.year.to_string())
+        .join(format!("d{}", req.day));
+    let (text, fetched) = match fs::read_to_string(&cached) {
+        Ok(text) => (text, Fetched::Cache),
+        Err(_) => {
+            let text = download(req)?;
+            write(&cached, &text).map_err(|e| format!("couldn't cache input: {e}"))?;
+            (text, Fetched::Download)
+        }
+    };
+
+    write(req.input, &text).map_err(|e| format!("couldn't write {}: {e}", req.input.display()))?;
+    Ok(fetched)
+}
+
+fn download(req: &Request) -> Result<String, String> {
+    let url = format!("{}/{}/day/{}/input", req.url, req.year, req.day);
+
+    let mut request = ureq::get(&url).set("User-Agent", USER_AGENT);
+    match req.session {
+        Some(session) => request = request.set("Cookie", &format!("session={session}")),
+        None if req.url == DEFAULT_URL => return Err("please set AOC_SESSION".into()),
+        None => {}
+    }
+
+    let (status, body) = match request.call() {
+        Ok(response) => (response.status(), response.into_string()),
+        Err(ureq::Error::Status(status, response)) => (status, response.into_string()),
+        Err(e) => return Err(format!("request to {url} failed: {e}")),
+    };
+    let body = body.map_err(|e| format!("couldn't read response: {e}"))?;
+
+    validate(status, &body)?;
+    Ok(body)
+}
+
+/// Check that a response is a puzzle input, rather than one of the site's error pages.
+fn validate(status: u16, body: &str) -> Result<(), String> {
+    let trimmed = body.trim_start();
+    let msg = if status == 429 || body.contains("too many requests") {
+        "rate limited; wait a while before trying again".to_string()
+    } else if body.contains("before it unlocks") {
+        "the puzzle hasn't unlocked yet".to_string()
+    } else if body.contains("Please log in") {
+        "not logged in; check AOC_SESSION".to_string()
+    } else if status != 200 {
+        format!(
+            "got HTTP {status}: {}",
+            body.lines().next().unwrap_or_default()
+        )
+    } else if trimmed.starts_with('<') || body.contains("<html") {
+        "got an HTML page instead of an input".to_string()
+    } else if trimmed.is_empty() {
+        "the input is empty".to_string()
+    } else {
+        return Ok(());
+    };
+
+    Err(msg)
+}
+
+/// Write a file, creating its directory if needed.
+fn write(path: &Path, text: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, text)
+}
+
+fn default_cache() -> PathBuf {
+    config::var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| config::var("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(|| PathBuf::from(".cache"))
+        .join("aoc")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::serve;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2022-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let (url, requests) = serve(200, "1000\n2000\n");
+        let dir = temp_dir("cache");
+        let input = dir.join("input/d1");
+        let cache = dir.join("cache");
+        let req = Request {
+            year: 2022,
+            day: 1,
+            url: &url,
+            session: Some("abc"),
+            input: &input,
+            cache: &cache,
+        };
+
+        assert_eq!(fetch_input(&req), Ok(Fetched::Download));
+        assert_eq!(fs::read_to_string(&input).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            fs::read_to_string(cache.join("2022/d1")).unwrap(),
+            "1000\n2000\n"
+        );
+        let request = requests.lock().unwrap()[0].clone();
+        assert!(request.starts_with("GET /2022/day/1/input "));
+        assert!(request.contains("session=abc"));
+
+        // an existing input isn't touched, and a missing one is restored from the cache
+        assert_eq!(fetch_input(&req), Ok(Fetched::Exists));
+        fs::remove_file(&input).unwrap();
+        assert_eq!(fetch_input(&req), Ok(Fetched::Cache));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reject_error_pages() {
+        let (url, _) = serve(200, "<!DOCTYPE html>\n<html><body>404</body></html>");
+        let dir = temp_dir("reject");
+        let input = dir.join("input/d2");
+        let cache = dir.join("cache");
+        let req = Request {
+            year: 2022,
+            day: 2,
+            url: &url,
+            session: None,
+            input: &input,
+            cache: &cache,
+        };
+
+        assert!(fetch_input(&req).unwrap_err().contains("HTML"));
+        assert!(!input.exists());
+        assert!(!cache.join("2022/d2").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn validate_responses() {
+        assert!(validate(200, "1-3 a: abcde\n").is_ok());
+        assert!(validate(429, "").unwrap_err().contains("rate limited"));
+        assert!(validate(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!"
+        )
+        .unwrap_err()
+        .contains("unlocked"));
+        assert!(validate(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+        )
+        .unwrap_err()
+        .contains("AOC_SESSION"));
+        assert!(validate(500, "oops").unwrap_err().contains("HTTP 500"));
+        assert!(validate(200, "\n").unwrap_err().contains("empty"));
+    }
+}
@@ -0,0 +1,391 @@
+//! 2D geometry for the grid puzzles: points, directions and turns, distances, neighbours, and
+//! bounding boxes.
+//!
+//! Coordinates follow the puzzles' pictures, so y grows downwards: [`Dir::Up`] is -y, and turning
+//! clockwise takes you from up to right.
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A type that can be used for coordinates: any of the primitive integers.
+pub trait Coord:
+    Copy + Ord + Hash + Debug + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// How far apart two values are.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+        }
+    )*};
+}
+
+coord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Point<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The distance a king would take, moving diagonally as well.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// Move `dx` and `dy` (each -1, 0 or 1) steps, if that doesn't leave `T`'s range.
+    fn shift(self, dx: i8, dy: i8) -> Option<Self> {
+        let shift = |v: T, d: i8| match d {
+            -1 => v.checked_sub(T::ONE),
+            1 => v.checked_add(T::ONE),
+            _ => Some(v),
+        };
+        Some(Self::new(shift(self.x, dx)?, shift(self.y, dy)?))
+    }
+
+    /// One step in a direction, unless that leaves `T`'s range (like going up from y 0 with
+    /// `usize`s).
+    pub fn step(self, dir: Dir) -> Option<Self> {
+        let (dx, dy) = dir.delta();
+        self.shift(dx, dy)
+    }
+
+    /// The four points next to this one, in [`Dir::ALL`] order.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().filter_map(move |dir| self.step(dir))
+    }
+
+    /// The eight points around this one, clockwise from the one above.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        const AROUND: [(i8, i8); 8] = [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ];
+        AROUND
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.shift(dx, dy))
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Point<T> {
+    /// One step in a direction, as a point.
+    pub fn unit(dir: Dir) -> Self {
+        Self::new(T::ZERO, T::ZERO).step(dir).unwrap()
+    }
+
+    /// Turn the point around the origin.
+    pub fn rot(self, rot: Rot) -> Self {
+        match rot {
+            Rot::Cw => Self::new(-self.y, self.x),
+            Rot::Ccw => Self::new(self.y, -self.x),
+            Rot::Flip => -self,
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// One step in a direction.  Panics if that leaves `T`'s range; use [`Point::step`] where that
+/// can happen.
+impl<T: Coord> Add<Dir> for Point<T> {
+    type Output = Self;
+
+    fn add(self, dir: Dir) -> Self {
+        self.step(dir)
+            .unwrap_or_else(|| panic!("stepping {dir:?} from {self} goes out of range"))
+    }
+}
+
+impl<T: Coord> AddAssign<Dir> for Point<T> {
+    fn add_assign(&mut self, dir: Dir) {
+        *self = *self + dir;
+    }
+}
+
+/// As `x,y`.
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Clockwise from up.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn cw(self) -> Self {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    pub fn ccw(self) -> Self {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Right => Dir::Up,
+            Dir::Down => Dir::Right,
+            Dir::Left => Dir::Down,
+        }
+    }
+
+    pub fn flip(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Right => Dir::Left,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+        }
+    }
+
+    pub fn rot(self, rot: Rot) -> Self {
+        match rot {
+            Rot::Cw => self.cw(),
+            Rot::Ccw => self.ccw(),
+            Rot::Flip => self.flip(),
+        }
+    }
+
+    /// Which way x and y change going this way.
+    fn delta(self) -> (i8, i8) {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Right => (1, 0),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+        }
+    }
+}
+
+/// A turn.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Rot {
+    Cw,
+    Ccw,
+    /// Half way around.
+    Flip,
+}
+
+impl Rot {
+    /// The turn that takes you from facing `from` to facing `to`, if they're different.
+    pub fn between(from: Dir, to: Dir) -> Option<Self> {
+        [Rot::Cw, Rot::Ccw, Rot::Flip]
+            .into_iter()
+            .find(|&rot| from.rot(rot) == to)
+    }
+
+    /// The turn that undoes this one.
+    pub fn inverse(self) -> Self {
+        match self {
+            Rot::Cw => Rot::Ccw,
+            Rot::Ccw => Rot::Cw,
+            Rot::Flip => Rot::Flip,
+        }
+    }
+}
+
+/// The smallest rectangle around some points, edges included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<T = i32> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coord> Bounds<T> {
+    /// The bounds of a single point.
+    pub fn new(p: Point<T>) -> Self {
+        Self { min: p, max: p }
+    }
+
+    /// The bounds of every point, unless there aren't any.
+    pub fn of(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for p in points {
+            bounds.include(p);
+        }
+        Some(bounds)
+    }
+
+    /// Grow to take in another point.
+    pub fn include(&mut self, p: Point<T>) {
+        self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(Point::<u8>::new(200, 3).manhattan(Point::new(0, 10)), 207);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir::Up.cw(), Dir::Right);
+        assert_eq!(Dir::Up.ccw(), Dir::Left);
+        assert_eq!(Dir::Left.flip(), Dir::Right);
+        for dir in Dir::ALL {
+            assert_eq!(dir.cw().ccw(), dir);
+            assert_eq!(dir.rot(Rot::Flip), dir.cw().cw());
+            for rot in [Rot::Cw, Rot::Ccw, Rot::Flip] {
+                assert_eq!(Rot::between(dir, dir.rot(rot)), Some(rot));
+                assert_eq!(dir.rot(rot).rot(rot.inverse()), dir);
+                // turning a direction's step turns the direction
+                assert_eq!(Point::<i32>::unit(dir).rot(rot), Point::unit(dir.rot(rot)));
+            }
+        }
+        assert_eq!(Rot::between(Dir::Down, Dir::Down), None);
+    }
+
+    #[test]
+    fn steps_and_neighbours() {
+        let p = Point::new(3, 4);
+        assert_eq!(p + Dir::Up, Point::new(3, 3));
+        assert_eq!(Point::unit(Dir::Left) * 2 + p, Point::new(1, 4));
+        assert_eq!(Point::<usize>::new(0, 0).step(Dir::Up), None);
+
+        let corner: Vec<_> = Point::<usize>::new(0, 0).neighbours4().collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        let around: Vec<_> = Point::<u8>::new(0, 1).neighbours8().collect();
+        assert_eq!(
+            around,
+            [
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(1, 2),
+                Point::new(0, 2),
+            ]
+        );
+        assert_eq!(p.neighbours8().count(), 8);
+        assert!(p.neighbours8().all(|n| n.chebyshev(p) == 1));
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let points = [Point::new(2, -1), Point::new(-3, 4), Point::new(0, 0)];
+        let bounds = Bounds::of(points).unwrap();
+        assert_eq!(bounds.min, Point::new(-3, -1));
+        assert_eq!(bounds.max, Point::new(2, 4));
+        assert_eq!((bounds.width(), bounds.height()), (6, 6));
+        assert!(bounds.contains(Point::new(-3, 4)));
+        assert!(!bounds.contains(Point::new(3, 0)));
+        assert_eq!(Bounds::<i32>::of([]), None);
+    }
+}
@@ -0,0 +1,298 @@
+//! A dense, rectangular grid of cells, for the days whose input is a picture.
+//!
+//! Cells are addressed by [`Point`]s, x across and y down from the top left.  Lookups take points
+//! of any integer type and return `None` off the edge, so stepping off the grid from a signed
+//! position is just a lookup that fails, not a bounds check (or an underflow) of its own.
+
+use crate::geom::{Dir, Point, Rot};
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row by row, from the top.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A grid with each cell made by `f`, row by row from the top left.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point<usize>) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// One cell per character, through the cell's `From<char>`.  See [`Grid::parse_with`].
+    pub fn parse(input: &str) -> Self
+    where
+        T: From<char>,
+    {
+        Self::parse_with(input, T::from)
+    }
+
+    /// One cell per character of each line.  Short lines are padded out with spaces, so ragged
+    /// input still makes a rectangle.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let width = input.lines().map(|line| line.chars().count()).max();
+        let width = width.unwrap_or(0);
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in input.lines() {
+            let len = line.chars().count();
+            cells.extend(line.chars().chain((len..width).map(|_| ' ')).map(&mut f));
+            height += 1;
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Where a point's cell is in `cells`, if it's on the grid.
+    fn offset_of<C: TryInto<usize>>(&self, p: Point<C>) -> Option<usize> {
+        let x = p.x.try_into().ok()?;
+        let y = p.y.try_into().ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains<C: TryInto<usize>>(&self, p: Point<C>) -> bool {
+        self.offset_of(p).is_some()
+    }
+
+    /// The cell at a point, of any integer type, if it's on the grid.
+    pub fn get<C: TryInto<usize>>(&self, p: Point<C>) -> Option<&T> {
+        self.offset_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<C: TryInto<usize>>(&mut self, p: Point<C>) -> Option<&mut T> {
+        self.offset_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// The point `dx` and `dy` away, if it's on the grid.
+    pub fn offset(&self, p: Point<usize>, dx: isize, dy: isize) -> Option<Point<usize>> {
+        let q = Point::new(p.x.checked_add_signed(dx)?, p.y.checked_add_signed(dy)?);
+        self.contains(q).then_some(q)
+    }
+
+    /// The next point over, if it's on the grid.
+    pub fn step(&self, p: Point<usize>, dir: Dir) -> Option<Point<usize>> {
+        p.step(dir).filter(|&q| self.contains(q))
+    }
+
+    /// Every point from the one next to `p` to the edge of the grid, going one way.
+    pub fn ray(&self, p: Point<usize>, dir: Dir) -> impl Iterator<Item = Point<usize>> + '_ {
+        std::iter::successors(self.step(p, dir), move |&q| self.step(q, dir))
+    }
+
+    /// The points next to `p` that are on the grid, in [`Dir::ALL`] order.
+    pub fn neighbours4(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        p.neighbours4().filter(|&q| self.contains(q))
+    }
+
+    /// The points around `p` that are on the grid, clockwise from the one above.
+    pub fn neighbours8(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        p.neighbours8().filter(|&q| self.contains(q))
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<usize>, &mut T)> {
+        self.points().zip(&mut self.cells)
+    }
+
+    /// The first point, row by row, whose cell matches.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point<usize>> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(p, _)| p)
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks of 0 aren't allowed, but an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of one column, from the top.  Panics if x is off the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is off a grid {} wide",
+            self.width
+        );
+        self.rows().map(move |row| &row[x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// A grid of the same size, with each cell passed through `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Flip over the diagonal from the top left, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Turn the whole grid.
+    pub fn rotate(&self, rot: Rot) -> Self
+    where
+        T: Clone,
+    {
+        let (w, h) = (self.width, self.height);
+        match rot {
+            Rot::Cw => Self::from_fn(h, w, |p| self[Point::new(p.y, h - 1 - p.x)].clone()),
+            Rot::Ccw => Self::from_fn(h, w, |p| self[Point::new(w - 1 - p.y, p.x)].clone()),
+            Rot::Flip => {
+                Self::from_fn(w, h, |p| self[Point::new(w - 1 - p.x, h - 1 - p.y)].clone())
+            }
+        }
+    }
+
+    /// Draw the grid as text, one character per cell and a newline after every row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut f));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<usize>) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p} is off a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p} is off a {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_render() {
+        let grid: Grid<char> = Grid::parse("ab\nc\n\ndef");
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.render(|&c| c), "ab \nc  \n   \ndef\n");
+
+        let digits = Grid::parse_with("30\n25", |c| c.to_digit(10));
+        assert_eq!(digits[Point::new(1, 1)], Some(5));
+        assert_eq!(
+            digits.render(|d| if *d == Some(0) { '!' } else { '.' }),
+            ".!\n..\n"
+        );
+        assert_eq!(Grid::<char>::parse("").rows().count(), 0);
+    }
+
+    #[test]
+    fn lookups() {
+        let mut grid = Grid::from_fn(3, 2, |p| p.x + 10 * p.y);
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&12));
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(3_i64, 0)), None);
+        assert_eq!(grid.offset(Point::new(1, 1), 1, -1), Some(Point::new(2, 0)));
+        assert_eq!(grid.offset(Point::new(0, 0), -1, 0), None);
+        *grid.get_mut(Point::new(0u8, 1)).unwrap() = 99;
+        grid[Point::new(1, 0)] += 1;
+        assert_eq!(grid.position(|&v| v > 50), Some(Point::new(0, 1)));
+        assert_eq!(
+            grid.iter().map(|(_, v)| v).sum::<usize>(),
+            2 + 2 + 99 + 11 + 12
+        );
+    }
+
+    #[test]
+    fn walking() {
+        let grid = Grid::new(4, 3, ());
+        let corner: Vec<_> = grid.neighbours4(Point::new(3, 0)).collect();
+        assert_eq!(corner, [Point::new(3, 1), Point::new(2, 0)]);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(0, 2)).count(), 3);
+
+        let ray: Vec<_> = grid.ray(Point::new(1, 1), Dir::Right).collect();
+        assert_eq!(ray, [Point::new(2, 1), Point::new(3, 1)]);
+        assert_eq!(grid.ray(Point::new(1, 0), Dir::Up).count(), 0);
+        assert_eq!(grid.step(Point::new(1, 2), Dir::Down), None);
+    }
+
+    #[test]
+    fn rows_columns_and_turns() {
+        let grid: Grid<char> = Grid::parse("abc\ndef");
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["abc", "def"]);
+        let columns: Vec<String> = grid.columns().map(|col| col.rev().collect()).collect();
+        assert_eq!(columns, ["da", "eb", "fc"]);
+
+        let render = |grid: Grid<char>| grid.render(|&c| c);
+        assert_eq!(render(grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(render(grid.rotate(Rot::Cw)), "da\neb\nfc\n");
+        assert_eq!(render(grid.rotate(Rot::Ccw)), "cf\nbe\nad\n");
+        assert_eq!(render(grid.rotate(Rot::Flip)), "fed\ncba\n");
+        assert_eq!(grid.rotate(Rot::Cw).rotate(Rot::Ccw), grid);
+    }
+}
@@ -0,0 +1,270 @@
+//! Sets of integers stored as runs, for puzzles that do arithmetic on ranges (overlapping
+//! assignments, sensor coverage along a row).
+//!
+//! An [`IntervalSet`] keeps its runs sorted, with at least one missing value between each run and
+//! the next, so two sets holding the same values always compare equal.
+
+use crate::geom::Coord;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Inclusive starts and ends, sorted, neither overlapping nor touching.
+    runs: Vec<(T, T)>,
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { runs: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    /// The runs, in order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.runs.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// How many values are in the set.
+    pub fn total_len(&self) -> T {
+        self.runs
+            .iter()
+            .fold(T::ZERO, |len, &(start, end)| len + (end - start) + T::ONE)
+    }
+
+    /// The runs of missing values between the first value and the last.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.runs
+            .windows(2)
+            .map(|pair| (pair[0].1 + T::ONE)..=(pair[1].0 - T::ONE))
+    }
+
+    /// The run holding a value, if it's in the set.
+    pub fn get(&self, value: T) -> Option<RangeInclusive<T>> {
+        let i = self.runs.partition_point(|&(_, end)| end < value);
+        let &(start, end) = self.runs.get(i)?;
+        (start <= value).then_some(start..=end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.get(value).is_some()
+    }
+
+    /// Add every value in a range, joining it up with any runs it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // the runs from first up to last overlap or touch the new one
+        let first = self
+            .runs
+            .partition_point(|&(_, e)| e.checked_add(T::ONE).is_some_and(|after| after < start));
+        let last = self
+            .runs
+            .partition_point(|&(s, _)| s.checked_sub(T::ONE).is_none_or(|before| before <= end));
+        if first < last {
+            start = start.min(self.runs[first].0);
+            end = end.max(self.runs[last - 1].1);
+        }
+        self.runs.splice(first..last, [(start, end)]);
+    }
+
+    /// Take out every value in a range, splitting any run it falls inside.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // the runs from first up to last overlap the range
+        let first = self.runs.partition_point(|&(_, e)| e < start);
+        let last = self.runs.partition_point(|&(s, _)| s <= end);
+        if first == last {
+            return;
+        }
+        let mut left = None;
+        let mut right = None;
+        if self.runs[first].0 < start {
+            left = Some((self.runs[first].0, start - T::ONE));
+        }
+        if self.runs[last - 1].1 > end {
+            right = Some((end + T::ONE, self.runs[last - 1].1));
+        }
+        self.runs.splice(first..last, left.into_iter().chain(right));
+    }
+
+    /// Every value in either set.
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        merged.extend(other.iter());
+        merged
+    }
+
+    /// Every value in this set but not the other.
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut left = self.clone();
+        for range in other.iter() {
+            left.remove(range);
+        }
+        left
+    }
+
+    /// Every value in both sets.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut both = Self::new();
+        let (mut a, mut b) = (self.runs.iter().peekable(), other.runs.iter().peekable());
+        while let (Some(&&(a_start, a_end)), Some(&&(b_start, b_end))) = (a.peek(), b.peek()) {
+            both.insert(a_start.max(b_start)..=a_end.min(b_end));
+            // whichever run ends first can't overlap anything else
+            if a_end < b_end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        both
+    }
+}
+
+impl<T: Coord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Coord> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Coord> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn runs_join_and_split() {
+        let mut set: IntervalSet<i32> = [1..=3, 10..=12, 5..=5].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1..=3, 5..=5, 10..=12]);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [4..=4, 6..=9]);
+
+        // touching runs join up
+        set.insert(4..=4);
+        set.insert(13..=20);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1..=5, 10..=20]);
+        assert_eq!(set.total_len(), 16);
+        assert_eq!(set.get(15), Some(10..=20));
+        assert_eq!(set.get(7), None);
+
+        set.remove(12..=14);
+        set.remove(-5..=1);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [2..=5, 10..=11, 15..=20]);
+
+        let other = IntervalSet::from(4..=16);
+        assert_eq!(
+            set.intersect(&other).iter().collect::<Vec<_>>(),
+            [4..=5, 10..=11, 15..=16]
+        );
+        assert_eq!(set.merge(&other), IntervalSet::from(2..=20));
+        assert_eq!(
+            set.subtract(&other).iter().collect::<Vec<_>>(),
+            [2..=3, 17..=20]
+        );
+    }
+
+    #[test]
+    fn the_ends_of_the_type() {
+        let mut set = IntervalSet::from(0u8..=10);
+        set.insert(250..=255);
+        set.insert(11..=249);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0..=255]);
+        set.remove(0..=0);
+        set.remove(255..=255);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1..=254]);
+        set.insert(RangeInclusive::new(9, 3));
+        assert_eq!(set.total_len(), 254);
+    }
+
+    /// A set of values below 64, one bit each.
+    fn bits(set: &IntervalSet<u8>) -> u64 {
+        set.iter()
+            .flatten()
+            .fold(0, |bits, value| bits | 1 << value)
+    }
+
+    fn apply(ops: &[(bool, u8, u8)]) -> (IntervalSet<u8>, u64) {
+        let mut set = IntervalSet::new();
+        let mut model = 0u64;
+        for &(insert, a, b) in ops {
+            let (start, end) = (a.min(b), a.max(b));
+            let range_bits = (start..=end).fold(0, |bits, value| bits | 1 << value);
+            if insert {
+                set.insert(start..=end);
+                model |= range_bits;
+            } else {
+                set.remove(start..=end);
+                model &= !range_bits;
+            }
+        }
+        (set, model)
+    }
+
+    fn ops() -> impl Strategy<Value = Vec<(bool, u8, u8)>> {
+        prop::collection::vec((any::<bool>(), 0u8..64, 0u8..64), 0..20)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_a_bitset(ops in ops()) {
+            let (set, model) = apply(&ops);
+            prop_assert_eq!(bits(&set), model);
+            prop_assert_eq!(set.total_len() as u32, model.count_ones());
+            for value in 0..64u8 {
+                prop_assert_eq!(set.contains(value), model & 1 << value != 0);
+            }
+
+            // runs are sorted with room between them, and the gaps are what's between them
+            for pair in set.runs.windows(2) {
+                prop_assert!(pair[0].0 <= pair[0].1 && pair[0].1 + 1 < pair[1].0);
+            }
+            let gaps = set.gaps().flatten().fold(0u64, |bits, value| bits | 1 << value);
+            let span = match set.iter().next().zip(set.iter().last()) {
+                Some((first, last)) => (*first.start()..=*last.end()).fold(0, |bits, v| bits | 1 << v),
+                None => 0,
+            };
+            prop_assert_eq!(gaps, span & !model);
+        }
+
+        #[test]
+        fn set_operations_match_a_bitset(a in ops(), b in ops()) {
+            let ((a, a_bits), (b, b_bits)) = (apply(&a), apply(&b));
+            prop_assert_eq!(bits(&a.merge(&b)), a_bits | b_bits);
+            prop_assert_eq!(bits(&a.subtract(&b)), a_bits & !b_bits);
+            prop_assert_eq!(bits(&a.intersect(&b)), a_bits & b_bits);
+            // the same values make the same set, however they got there
+            prop_assert_eq!(a.intersect(&b), b.intersect(&a));
+            prop_assert_eq!(a.merge(&b), b.merge(&a));
+        }
+    }
+}
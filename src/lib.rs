@@ -3,28 +3,44 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(unused)]
 #![feature(int_roundings)]
-pub mod d1;
-pub mod d10;
-pub mod d11;
-pub mod d12;
-pub mod d13;
-pub mod d14;
-pub mod d15;
-pub mod d16;
-pub mod d17;
-pub mod d18;
-pub mod d19;
-pub mod d2;
-pub mod d20;
-pub mod d21;
-pub mod d22;
-pub mod d23;
-pub mod d24;
-pub mod d25;
-pub mod d3;
-pub mod d4;
-pub mod d5;
-pub mod d6;
-pub mod d7;
-pub mod d8;
-pub mod d9;
+pub mod answers;
+pub mod error;
+pub mod examples;
+pub mod geom;
+pub mod grid;
+pub mod intervals;
+pub mod paths;
+pub mod solution;
+pub mod sparse;
+pub mod vis;
+pub mod voxels;
+pub mod y2022;
+
+pub use error::{AocError, Result};
+pub use solution::{DynSolution, Run, Solution};
+
+/// The year used when none is given.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// One year's solutions.
+pub struct Year {
+    pub year: u16,
+    /// Every day's solution, in day order.
+    pub solutions: &'static [&'static dyn DynSolution],
+}
+
+/// Every year's solutions, in year order.  Each year's days live in its `yYYYY` module.
+pub static YEARS: &[Year] = &[Year {
+    year: 2022,
+    solutions: y2022::SOLUTIONS,
+}];
+
+/// Look up the solutions for a given year.
+pub fn solutions(year: u16) -> Option<&'static [&'static dyn DynSolution]> {
+    YEARS.iter().find(|y| y.year == year).map(|y| y.solutions)
+}
+
+/// Look up the solution for a given day.
+pub fn solution(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    solutions(year)?.iter().copied().find(|s| s.day() == day)
+}
@@ -1,303 +1,175 @@
 mod args;
+mod bench;
+mod config;
+mod fetch;
+#[cfg(test)]
+mod mock;
+mod repl;
+mod report;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
 
-use std::{fs::read_to_string, process::exit};
+use aoc2022::{
+    answers::{Answers, Verdict},
+    examples, paths,
+    vis::{self, Recording},
+    Run,
+};
+use args::{Args, Command, Format};
+use runner::{Input, InputCache, Task};
+use std::{env, process::exit, time::Instant};
 
 fn main() {
-    let args = args::parse_args().unwrap_or_else(|_| {
+    let command = args::parse_args().unwrap_or_else(|_| {
         eprintln!("Error: parsing CLI arguments failed");
         print!("{}", args::HELP);
         std::process::exit(1);
     });
 
-    // day 255 is a magic day number meaning "run all days"
-    if args.day == 255 {
-        for day in 1..=25 {
-            let input =
-                read_to_string(format!("./input/d{}", day)).expect("couldn't read input file");
-            print!("day {day} part 1: ");
-            run(day, 1, input.clone());
-            print!("day {day} part 2: ");
-            run(day, 2, input.clone());
-        }
-    } else if (1..=25).contains(&args.day) {
-        let input_file = if args.input.is_some() {
-            args.input.unwrap()
-        } else if args.example {
-            format!("./examples/d{}", args.day)
-        } else {
-            format!("./input/d{}", args.day)
-        };
+    let args = match command {
+        Command::Run(args) => args,
+        Command::Bench(args) => exit(if bench::bench(args) { 0 } else { 1 }),
+        Command::New(args) => exit(if scaffold::new_day(args) { 0 } else { 1 }),
+        Command::Fetch(args) => exit(if fetch::fetch(args) { 0 } else { 1 }),
+        Command::Submit(args) => exit(if submit::submit(args) { 0 } else { 1 }),
+        Command::Repl(year) => exit(if repl::repl(year) { 0 } else { 1 }),
+    };
 
-        if let Ok(input) = read_to_string(input_file) {
-            run(args.day, args.part, input);
-        } else {
-            eprintln!(
-                "Error: input file for day {} is missing or unreadable",
-                args.day
-            );
-        }
-    } else {
-        eprintln!("Error: DAY must be 1 through 25, or 255 to run all days");
+    if args.watch_tests {
+        watch::watch(args.year, args.day, watch::Target::Tests);
     }
+    if args.watch {
+        // each run is a fresh `cargo run` with the same arguments, so source changes get rebuilt
+        let args_again = env::args().skip(1).filter(|arg| arg != "--watch").collect();
+        watch::watch(args.year, args.day, watch::Target::Run(args_again));
+    }
+
+    exit(if run(&args, &mut InputCache::default()) {
+        0
+    } else {
+        1
+    });
 }
 
-fn run(day: u8, part: u8, input: String) {
-    if ![1, 2].contains(&part) {
-        eprintln!("Error: part must be 1 or 2");
-        exit(1);
+/// Run the days and parts `args` asks for and print their answers.  Returns false if anything
+/// failed.  The REPL runs every command through here too, with an input cache that it keeps.
+pub fn run(args: &Args, cache: &mut InputCache) -> bool {
+    let tasks = match tasks(args) {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return false;
+        }
+    };
+
+    if let Some(path) = &args.record {
+        vis::record(Recording::Cast(path.into()));
+    } else if let Some(dir) = &args.frames {
+        vis::record(Recording::Images(dir.into(), args.frames_format));
+    }
+    if let Some(path) = &args.svg {
+        vis::snapshot_to(path);
     }
 
-    match (day, part) {
-        (1, 1) => {
-            let parsed = aoc2022::d1::parse(input);
-            let output = aoc2022::d1::part1(parsed);
-            println!("{output}");
-        }
-        (1, 2) => {
-            let parsed = aoc2022::d1::parse(input);
-            let output = aoc2022::d1::part2(parsed);
-            println!("{output}");
-        }
-        (2, 1) => {
-            let parsed = aoc2022::d2::parse(input);
-            let output = aoc2022::d2::part1(parsed);
-            println!("{output}");
-        }
-        (2, 2) => {
-            let parsed = aoc2022::d2::parse(input);
-            let output = aoc2022::d2::part2(parsed);
-            println!("{output}");
-        }
-        (3, 1) => {
-            let parsed = aoc2022::d3::parse(input);
-            let output = aoc2022::d3::part1(parsed);
-            println!("{output}");
-        }
-        (3, 2) => {
-            let parsed = aoc2022::d3::parse(input);
-            let output = aoc2022::d3::part2(parsed);
-            println!("{output}");
-        }
-        (4, 1) => {
-            let parsed = aoc2022::d4::parse(input);
-            let output = aoc2022::d4::part1(parsed);
-            println!("{output}");
-        }
-        (4, 2) => {
-            let parsed = aoc2022::d4::parse(input);
-            let output = aoc2022::d4::part2(parsed);
-            println!("{output}");
-        }
-        (5, 1) => {
-            let parsed = aoc2022::d5::parse(input);
-            let output = aoc2022::d5::part1(parsed);
-            println!("{output}");
-        }
-        (5, 2) => {
-            let parsed = aoc2022::d5::parse(input);
-            let output = aoc2022::d5::part2(parsed);
-            println!("{output}");
-        }
-        (6, 1) => {
-            let parsed = aoc2022::d6::parse(input);
-            let output = aoc2022::d6::part1(parsed);
-            println!("{output}");
-        }
-        (6, 2) => {
-            let parsed = aoc2022::d6::parse(input);
-            let output = aoc2022::d6::part2(parsed);
-            println!("{output}");
-        }
-        (7, 1) => {
-            let parsed = aoc2022::d7::parse(input);
-            let output = aoc2022::d7::part1(parsed);
-            println!("{output}");
-        }
-        (7, 2) => {
-            let parsed = aoc2022::d7::parse(input);
-            let output = aoc2022::d7::part2(parsed);
-            println!("{output}");
-        }
-        (8, 1) => {
-            let parsed = aoc2022::d8::parse(input);
-            let output = aoc2022::d8::part1(parsed);
-            println!("{output}");
-        }
-        (8, 2) => {
-            let parsed = aoc2022::d8::parse(input);
-            let output = aoc2022::d8::part2(parsed);
-            println!("{output}");
-        }
-        (9, 1) => {
-            let parsed = aoc2022::d9::parse(input);
-            let output = aoc2022::d9::part1(parsed);
-            println!("{output}");
-        }
-        (9, 2) => {
-            let parsed = aoc2022::d9::parse(input);
-            let output = aoc2022::d9::part2(parsed);
-            println!("{output}");
-        }
-        (10, 1) => {
-            let parsed = aoc2022::d10::parse(input);
-            let output = aoc2022::d10::part1(parsed);
-            println!("{output}");
-        }
-        (10, 2) => {
-            let parsed = aoc2022::d10::parse(input);
-            let output = aoc2022::d10::part2(parsed);
-            println!("{output}");
-        }
-        (11, 1) => {
-            let parsed = aoc2022::d11::parse(input);
-            let output = aoc2022::d11::part1(parsed);
-            println!("{output}");
-        }
-        (11, 2) => {
-            let parsed = aoc2022::d11::parse(input);
-            let output = aoc2022::d11::part2(parsed);
-            println!("{output}");
-        }
-        (12, 1) => {
-            let parsed = aoc2022::d12::parse(input);
-            let output = aoc2022::d12::part1(parsed);
-            println!("{output}");
-        }
-        (12, 2) => {
-            let parsed = aoc2022::d12::parse(input);
-            let output = aoc2022::d12::part2(parsed);
-            println!("{output}");
-        }
-        (13, 1) => {
-            let parsed = aoc2022::d13::parse(input);
-            let output = aoc2022::d13::part1(parsed);
-            println!("{output}");
-        }
-        (13, 2) => {
-            let parsed = aoc2022::d13::parse(input);
-            let output = aoc2022::d13::part2(parsed);
-            println!("{output}");
-        }
-        (14, 1) => {
-            let parsed = aoc2022::d14::parse(input);
-            let output = aoc2022::d14::part1(parsed);
-            println!("{output}");
-        }
-        (14, 2) => {
-            let parsed = aoc2022::d14::parse(input);
-            let output = aoc2022::d14::part2(parsed);
-            println!("{output}");
-        }
-        (15, 1) => {
-            let parsed = aoc2022::d15::parse(input);
-            let output = aoc2022::d15::part1(parsed);
-            println!("{output}");
-        }
-        (15, 2) => {
-            let parsed = aoc2022::d15::parse(input);
-            let output = aoc2022::d15::part2(parsed);
-            println!("{output}");
-        }
-        (16, 1) => {
-            let parsed = aoc2022::d16::parse(&input);
-            let output = aoc2022::d16::part1(parsed);
-            println!("{output}");
-        }
-        (16, 2) => {
-            let parsed = aoc2022::d16::parse(&input);
-            let output = aoc2022::d16::part2(parsed);
-            println!("{output}");
-        }
-        (17, 1) => {
-            let parsed = aoc2022::d17::parse(input);
-            let output = aoc2022::d17::part1(parsed);
-            println!("{output}");
-        }
-        (17, 2) => {
-            let parsed = aoc2022::d17::parse(input);
-            let output = aoc2022::d17::part2(parsed);
-            println!("{output}");
-        }
-        (18, 1) => {
-            let parsed = aoc2022::d18::parse(input);
-            let output = aoc2022::d18::part1(parsed);
-            println!("{output}");
-        }
-        (18, 2) => {
-            let parsed = aoc2022::d18::parse(input);
-            let output = aoc2022::d18::part2(parsed);
-            println!("{output}");
-        }
-        (19, 1) => {
-            let parsed = aoc2022::d19::parse(input);
-            let output = aoc2022::d19::part1(parsed);
-            println!("{output}");
-        }
-        (19, 2) => {
-            let parsed = aoc2022::d19::parse(input);
-            let output = aoc2022::d19::part2(parsed);
-            println!("{output}");
-        }
-        (20, 1) => {
-            let parsed = aoc2022::d20::parse(input);
-            let output = aoc2022::d20::part1(parsed);
-            println!("{output}");
-        }
-        (20, 2) => {
-            let parsed = aoc2022::d20::parse(input);
-            let output = aoc2022::d20::part2(parsed);
-            println!("{output}");
-        }
-        (21, 1) => {
-            let parsed = aoc2022::d21::parse(input);
-            let output = aoc2022::d21::part1(parsed);
-            println!("{output}");
-        }
-        (21, 2) => {
-            let parsed = aoc2022::d21::parse(input);
-            let output = aoc2022::d21::part2(parsed);
-            println!("{output}");
-        }
-        (22, 1) => {
-            let parsed = aoc2022::d22::parse(input);
-            let output = aoc2022::d22::part1(parsed);
-            println!("{output}");
-        }
-        (22, 2) => {
-            let parsed = aoc2022::d22::parse(input);
-            let output = aoc2022::d22::part2(parsed);
-            println!("{output}");
-        }
-        (23, 1) => {
-            let parsed = aoc2022::d23::parse(input);
-            let output = aoc2022::d23::part1(parsed);
-            println!("{output}");
-        }
-        (23, 2) => {
-            let parsed = aoc2022::d23::parse(input);
-            let output = aoc2022::d23::part2(parsed);
-            println!("{output}");
-        }
-        (24, 1) => {
-            let parsed = aoc2022::d24::parse(input);
-            let output = aoc2022::d24::part1(parsed);
-            println!("{output}");
-        }
-        (24, 2) => {
-            let parsed = aoc2022::d24::parse(input);
-            let output = aoc2022::d24::part2(parsed);
-            println!("{output}");
-        }
-        (25, 1) => {
-            let parsed = aoc2022::d25::parse(input);
-            let output = aoc2022::d25::part1(parsed);
-            println!("{output}");
-        }
-        (25, 2) => {
-            let parsed = aoc2022::d25::parse(input);
-            let output = aoc2022::d25::part2(parsed);
-            println!("{output}");
-        }
-        _ => unimplemented!(),
+    let opts = runner::Options {
+        format: args.format,
+        // running every example always reports how each one compares to its recorded answers
+        check: args.check || args.all_examples,
+        labels: args.day == 255 || args.all_examples,
+        jobs: args.jobs,
+    };
+
+    let start = Instant::now();
+    let records = runner::run_tasks(&tasks, &opts, cache);
+    let wall_time = start.elapsed();
+
+    let failed = records
+        .iter()
+        .any(|record| record.result.is_err() || record.check == Some(Verdict::Fail));
+    let runs: Vec<Run> = records
+        .into_iter()
+        .filter_map(|record| record.result.ok())
+        .collect();
+
+    if args.time && args.format == Format::Text && !runs.is_empty() {
+        println!();
+        print!("{}", report::timing_table(&runs, wall_time));
+    }
+
+    !failed
+}
+
+/// Work out which days, parts and inputs `args` asks for.
+fn tasks(args: &Args) -> Result<Vec<Task>, String> {
+    if ![1, 2].contains(&args.part) {
+        return Err("part must be 1 or 2".into());
     }
+
+    let year = args.year;
+    let solutions = aoc2022::solutions(year).ok_or(format!("there are no solutions for {year}"))?;
+
+    let tasks = if args.day == 255 {
+        // day 255 is a magic day number meaning "run all days"
+        solutions
+            .iter()
+            .map(|solution| solution.day())
+            .map(|day| Task {
+                year,
+                day,
+                parts: vec![1, 2],
+                input: Input::File(paths::input(year, day)),
+                label: format!("day {day}"),
+                answers: Answers::for_day(year, day),
+            })
+            .collect()
+    } else if args.all_examples {
+        let tasks: Vec<Task> = examples::for_day(year, args.day)
+            .into_iter()
+            .map(|example| Task {
+                year,
+                day: args.day,
+                parts: vec![args.part],
+                input: Input::File(example.path.display().to_string()),
+                label: example.path.display().to_string(),
+                answers: example.answers,
+            })
+            .collect();
+        if tasks.is_empty() {
+            return Err(format!("no examples found for {year} day {}", args.day));
+        }
+        tasks
+    } else if (1..=25).contains(&args.day) {
+        let input = if let Some(text) = &args.input_str {
+            Input::Text(text.clone())
+        } else if let Some(input) = &args.input {
+            Input::from_arg(input)
+        } else if args.example {
+            Input::File(paths::example(year, args.day))
+        } else {
+            Input::File(paths::input(year, args.day))
+        };
+        // answers for the real puzzle input live in answers/, other files use a sidecar file, and
+        // stdin or inline input has none
+        let answers = match &input {
+            Input::File(_) if args.input.is_none() && !args.example => {
+                Answers::for_day(year, args.day)
+            }
+            Input::File(path) => Answers::load(examples::sidecar(path)),
+            Input::Stdin | Input::Text(_) => Answers::default(),
+        };
+        vec![Task {
+            year,
+            day: args.day,
+            parts: vec![args.part],
+            label: format!("day {}", args.day),
+            input,
+            answers,
+        }]
+    } else {
+        return Err("DAY must be 1 through 25, or 255 to run all days".into());
+    };
+
+    Ok(tasks)
 }
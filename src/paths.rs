@@ -0,0 +1,27 @@
+//! Where each year's files live.  Everything specific to a year is kept in a directory named after
+//! it, so several years can share one workspace.
+
+/// A day's puzzle input.
+pub fn input(year: u16, day: u8) -> String {
+    format!("./input/{year}/d{day}")
+}
+
+/// The directory holding a year's example inputs (see [`crate::examples`]).
+pub fn examples(year: u16) -> String {
+    format!("./examples/{year}")
+}
+
+/// A day's default example input.
+pub fn example(year: u16, day: u8) -> String {
+    format!("{}/d{day}", examples(year))
+}
+
+/// The recorded answers for a day's puzzle input (see [`crate::answers`]).
+pub fn answers(year: u16, day: u8) -> String {
+    format!("./answers/{year}/d{day}")
+}
+
+/// A day's source file.
+pub fn source(year: u16, day: u8) -> String {
+    format!("./src/y{year}/d{day}.rs")
+}
@@ -0,0 +1,192 @@
+//! `aoc repl`: run days and parts interactively.
+//!
+//! Every command is turned into the same [`Args`] the command line produces and run through
+//! [`crate::run`], so it behaves just like a one-off run.  Input files are cached between commands
+//! (`reload` forgets them), which makes trying a solution on several inputs quick.
+
+use crate::{args::Args, runner::InputCache};
+use std::io::{self, Write};
+
+const HELP: &str = "\
+Commands:
+  run <day> [part]           run a day on its input (part 1 by default)
+  example <day> [part]       run a day on its example, examples/YYYY/dN
+  examples <day> [part]      run a day on every one of its examples, checking their answers
+  input <day> <path> [part]  run a day on another input file
+  time <day> [part]          run a day on its input and print its timings
+  all                        run every day
+  year <year>                switch to another year
+  reload                     forget the cached inputs, so they're read again
+  help                       show this list
+  quit                       leave (so does ctrl-d)
+";
+
+/// A line the user typed.
+enum Line {
+    Run(Args),
+    Year(u16),
+    Reload,
+    Help,
+    Quit,
+    Empty,
+}
+
+pub fn repl(year: u16) -> bool {
+    let mut year = year;
+    let mut cache = InputCache::default();
+    let stdin = io::stdin();
+
+    println!("type `help` for the commands");
+    loop {
+        print!("{year}> ");
+        io::stdout().flush().ok();
+
+        let mut line = String::new();
+        match stdin.read_line(&mut line) {
+            Ok(0) => {
+                println!();
+                return true;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Error: couldn't read a command: {e}");
+                return false;
+            }
+        }
+
+        match parse_line(&line, year) {
+            Ok(Line::Run(args)) => {
+                crate::run(&args, &mut cache);
+            }
+            Ok(Line::Year(new_year)) if aoc2022::solutions(new_year).is_some() => year = new_year,
+            Ok(Line::Year(new_year)) => eprintln!("Error: there are no solutions for {new_year}"),
+            Ok(Line::Reload) => cache.clear(),
+            Ok(Line::Help) => print!("{HELP}"),
+            Ok(Line::Quit) => return true,
+            Ok(Line::Empty) => {}
+            Err(e) => eprintln!("Error: {e}"),
+        }
+    }
+}
+
+fn parse_line(line: &str, year: u16) -> Result<Line, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((&command, rest)) = words.split_first() else {
+        return Ok(Line::Empty);
+    };
+
+    let number = |i: usize, what: &str| -> Result<Option<u64>, String> {
+        rest.get(i)
+            .map(|word| word.parse().map_err(|_| format!("{what} must be a number")))
+            .transpose()
+    };
+    let day = |i| -> Result<u8, String> {
+        let day = number(i, "day")?.ok_or(format!("usage: {command} <day> [part]"))?;
+        u8::try_from(day).map_err(|_| "DAY must be 1 through 25".to_string())
+    };
+    let part = |i| -> Result<u8, String> {
+        u8::try_from(number(i, "part")?.unwrap_or(1)).map_err(|_| "part must be 1 or 2".into())
+    };
+    let at_most = |n: usize| {
+        if rest.len() > n {
+            Err(format!("too many arguments to {command}"))
+        } else {
+            Ok(())
+        }
+    };
+
+    let line = match command {
+        "run" | "example" | "examples" | "time" => {
+            at_most(2)?;
+            let mut args = Args::new(day(0)?, year);
+            args.part = part(1)?;
+            args.example = command.starts_with("example");
+            args.all_examples = command == "examples";
+            args.time = command == "time";
+            Line::Run(args)
+        }
+        "input" => {
+            at_most(3)?;
+            let mut args = Args::new(day(0)?, year);
+            let path = rest.get(1).ok_or("usage: input <day> <path> [part]")?;
+            if *path == "-" {
+                // stdin is where the commands come from
+                return Err("the REPL can't read input from stdin".into());
+            }
+            args.input = Some(path.to_string());
+            args.part = part(2)?;
+            Line::Run(args)
+        }
+        "all" => {
+            at_most(0)?;
+            Line::Run(Args::new(255, year))
+        }
+        "year" => {
+            at_most(1)?;
+            let year = number(0, "year")?.ok_or("usage: year <year>")?;
+            Line::Year(u16::try_from(year).map_err(|_| format!("there's no year {year}"))?)
+        }
+        "reload" => Line::Reload,
+        "help" | "?" => Line::Help,
+        "quit" | "exit" | "q" => Line::Quit,
+        _ => {
+            return Err(format!(
+                "unknown command {command}; type `help` for the list"
+            ))
+        }
+    };
+
+    Ok(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(line: &str) -> Args {
+        match parse_line(line, 2022) {
+            Ok(Line::Run(args)) => args,
+            Ok(_) => panic!("{line} isn't a run"),
+            Err(e) => panic!("{line}: {e}"),
+        }
+    }
+
+    #[test]
+    fn parse_commands() {
+        let args = run("run 16 2");
+        assert_eq!((args.day, args.part, args.year), (16, 2, 2022));
+        assert!(!args.example && !args.time && args.input.is_none());
+
+        let args = run("  example 22\n");
+        assert_eq!((args.day, args.part), (22, 1));
+        assert!(args.example && !args.all_examples);
+
+        assert!(run("examples 9 2").all_examples);
+        assert_eq!(
+            run("input 5 path/to/d5").input.as_deref(),
+            Some("path/to/d5")
+        );
+        assert_eq!(run("input 5 other 2").part, 2);
+        assert!(run("time 19").time);
+        assert_eq!(run("all").day, 255);
+
+        assert!(matches!(
+            parse_line("year 2021", 2022),
+            Ok(Line::Year(2021))
+        ));
+        assert!(matches!(parse_line("", 2022), Ok(Line::Empty)));
+        assert!(matches!(parse_line("reload", 2022), Ok(Line::Reload)));
+        assert!(matches!(parse_line("quit", 2022), Ok(Line::Quit)));
+    }
+
+    #[test]
+    fn reject_bad_commands() {
+        let err = |line| parse_line(line, 2022).err().unwrap();
+        assert_eq!(err("run"), "usage: run <day> [part]");
+        assert_eq!(err("run x"), "day must be a number");
+        assert_eq!(err("run 1 2 3"), "too many arguments to run");
+        assert_eq!(err("input 5"), "usage: input <day> <path> [part]");
+        assert_eq!(err("input 5 -"), "the REPL can't read input from stdin");
+        assert!(err("jump 5").starts_with("unknown command jump"));
+    }
+}
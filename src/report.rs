@@ -0,0 +1,223 @@
+use crate::args::Format;
+use aoc2022::{answers::Verdict, Run};
+use serde_json::json;
+use std::{fmt::Write, time::Duration};
+
+/// The outcome of running one part of one day against one input file.
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// The path of the input file.
+    pub input: String,
+    /// The run, or a description of what went wrong.
+    pub result: Result<Run, String>,
+    /// How the answer compares to the recorded one, when checking answers.
+    pub check: Option<Verdict>,
+}
+
+pub const TSV_HEADER: &str =
+    "day\tpart\tinput\tanswer\tparse_ns\tsolve_ns\telapsed_ns\terror\tcheck";
+
+/// Format a record as a bare answer, with its verdict when checking answers, or as an error.
+/// `expected` is the recorded answer, shown alongside a failing one.
+pub fn format_text(record: &Record, expected: Option<&str>) -> String {
+    match (&record.result, record.check) {
+        (Ok(run), Some(Verdict::Fail)) => format!(
+            "{} [fail, expected {}]",
+            run.answer,
+            expected.unwrap_or_default()
+        ),
+        (Ok(run), Some(verdict)) => format!("{} [{verdict}]", run.answer),
+        (Ok(run), None) => run.answer.clone(),
+        (Err(e), _) => format!("Error: {e}"),
+    }
+}
+
+/// Format a record as a line of JSON or TSV, or as text (see [`format_text`]).
+pub fn format_record(record: &Record, format: Format) -> String {
+    let (answer, parse, solve, error) = match &record.result {
+        Ok(run) => (
+            Some(run.answer.as_str()),
+            Some(run.parse_time.as_nanos()),
+            Some(run.solve_time.as_nanos()),
+            None,
+        ),
+        Err(e) => (None, None, None, Some(e.as_str())),
+    };
+    let elapsed = parse.zip(solve).map(|(parse, solve)| parse + solve);
+    let check = record.check.map(|verdict| verdict.to_string());
+
+    match format {
+        Format::Json => json!({
+            "day": record.day,
+            "part": record.part,
+            "input": record.input,
+            "answer": answer,
+            "parse_ns": parse,
+            "solve_ns": solve,
+            "elapsed_ns": elapsed,
+            "error": error,
+            "check": check,
+        })
+        .to_string(),
+        Format::Text => format_text(record, None),
+        Format::Tsv => {
+            // answers like day 10's part 2 span several lines
+            let escape = |s: &str| {
+                s.replace('\\', "\\\\")
+                    .replace('\n', "\\n")
+                    .replace('\t', "\\t")
+            };
+            let num = |n: Option<u128>| n.map(|n| n.to_string()).unwrap_or_default();
+            [
+                record.day.to_string(),
+                record.part.to_string(),
+                escape(&record.input),
+                escape(answer.unwrap_or_default()),
+                num(parse),
+                num(solve),
+                num(elapsed),
+                escape(error.unwrap_or_default()),
+                check.unwrap_or_default(),
+            ]
+            .join("\t")
+        }
+    }
+}
+
+/// Render a table of per-phase timings for the given runs, followed by a grand total.  The last
+/// column is each run's share of the grand total.  The wall clock time is shown too, since it's
+/// less than the grand total when runs are done in parallel.  Parse times are as [`Run`] measures
+/// them, so they include copying the input for days that parse an owned `String`.
+pub fn timing_table(runs: &[Run], wall_time: Duration) -> String {
+    let mut table = String::new();
+
+    let parse: Duration = runs.iter().map(|run| run.parse_time).sum();
+    let solve: Duration = runs.iter().map(|run| run.solve_time).sum();
+
+    let _ = writeln!(
+        table,
+        "{:>3} {:>4} {:>12} {:>12} {:>12} {:>6}",
+        "day", "part", "parse", "solve", "total", "share"
+    );
+
+    for run in runs {
+        let share = 100.0 * run.total_time().as_secs_f64() / (parse + solve).as_secs_f64();
+        let _ = writeln!(
+            table,
+            "{:>3} {:>4} {:>12.2?} {:>12.2?} {:>12.2?} {:>5.1}%",
+            run.day,
+            run.part,
+            run.parse_time,
+            run.solve_time,
+            run.total_time(),
+            share
+        );
+    }
+
+    let _ = writeln!(
+        table,
+        "{:>8} {:>12.2?} {:>12.2?} {:>12.2?}",
+        "total",
+        parse,
+        solve,
+        parse + solve
+    );
+    let _ = writeln!(table, "{:>8} {:>38.2?}", "wall", wall_time);
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(result: Result<Run, String>) -> Record {
+        Record {
+            day: 10,
+            part: 2,
+            input: "./input/d10".to_string(),
+            result,
+            check: None,
+        }
+    }
+
+    #[test]
+    fn json_record() {
+        let run = Run {
+            day: 10,
+            part: 2,
+            answer: "\n#.\n".to_string(),
+            parse_time: Duration::from_nanos(5),
+            solve_time: Duration::from_nanos(7),
+        };
+        assert_eq!(
+            format_record(&record(Ok(run)), Format::Json),
+            r##"{"answer":"\n#.\n","check":null,"day":10,"elapsed_ns":12,"error":null,"input":"./input/d10","parse_ns":5,"part":2,"solve_ns":7}"##
+        );
+    }
+
+    #[test]
+    fn tsv_record() {
+        let run = Run {
+            day: 10,
+            part: 2,
+            answer: "\n#.\n".to_string(),
+            parse_time: Duration::from_nanos(5),
+            solve_time: Duration::from_nanos(7),
+        };
+        assert_eq!(
+            format_record(&record(Ok(run)), Format::Tsv),
+            "10\t2\t./input/d10\t\\n#.\\n\t5\t7\t12\t\t"
+        );
+        assert_eq!(
+            format_record(&record(Err("missing input".to_string())), Format::Tsv),
+            "10\t2\t./input/d10\t\t\t\t\tmissing input\t"
+        );
+    }
+
+    #[test]
+    fn text_record() {
+        let run = Run {
+            day: 10,
+            part: 2,
+            answer: "42".to_string(),
+            parse_time: Duration::from_nanos(5),
+            solve_time: Duration::from_nanos(7),
+        };
+        let mut checked = record(Ok(run));
+        assert_eq!(format_record(&checked, Format::Text), "42");
+        checked.check = Some(Verdict::Pass);
+        assert_eq!(format_text(&checked, Some("42")), "42 [pass]");
+        checked.check = Some(Verdict::Fail);
+        assert_eq!(format_text(&checked, Some("41")), "42 [fail, expected 41]");
+        assert_eq!(
+            format_record(&record(Err("missing input".to_string())), Format::Text),
+            "Error: missing input"
+        );
+    }
+
+    #[test]
+    fn timing_table_columns_and_totals() {
+        let run = |day, part, parse, solve| Run {
+            day,
+            part,
+            answer: String::new(),
+            parse_time: Duration::from_millis(parse),
+            solve_time: Duration::from_millis(solve),
+        };
+        let runs = [run(1, 1, 1, 3), run(12, 2, 2, 14)];
+        let table = timing_table(&runs, Duration::from_millis(15));
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "day part        parse        solve        total  share",
+                "  1    1       1.00ms       3.00ms       4.00ms  20.0%",
+                " 12    2       2.00ms      14.00ms      16.00ms  80.0%",
+                "   total       3.00ms      17.00ms      20.00ms",
+                "    wall                                15.00ms",
+            ]
+        );
+    }
+}
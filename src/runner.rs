@@ -0,0 +1,252 @@
+use crate::{args::Format, report, report::Record};
+use aoc2022::answers::Answers;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::read_to_string,
+    io::{self, Read},
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Where a task's puzzle input comes from.
+pub enum Input {
+    File(String),
+    Stdin,
+    /// Input given directly on the command line.
+    Text(String),
+}
+
+impl Input {
+    /// Parse an `-i` argument, where "-" means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(path.to_string()),
+        }
+    }
+
+    fn read(&self) -> io::Result<String> {
+        match self {
+            Input::File(path) => read_to_string(path),
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Input::Text(text) => Ok(text.clone()),
+        }
+    }
+
+    /// How the input is identified in json and tsv output.
+    pub fn name(&self) -> &str {
+        match self {
+            Input::File(path) => path,
+            Input::Stdin => "-",
+            Input::Text(_) => "<input-str>",
+        }
+    }
+}
+
+/// Input files that have been read already, so running a day again doesn't read its input again.
+/// Stdin and inline inputs aren't cached, and neither are files that couldn't be read.
+#[derive(Default)]
+pub struct InputCache(HashMap<String, String>);
+
+impl InputCache {
+    fn read(&mut self, input: &Input) -> io::Result<String> {
+        let Input::File(path) = input else {
+            return input.read();
+        };
+        if let Some(text) = self.0.get(path) {
+            return Ok(text.clone());
+        }
+        let text = input.read()?;
+        self.0.insert(path.clone(), text.clone());
+        Ok(text)
+    }
+
+    /// Forget every cached input, so they're read again next time.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// A day to run, and which parts.
+pub struct Task {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<u8>,
+    pub input: Input,
+    /// Identifies the task in text output when running more than one.
+    pub label: String,
+    /// The answers to check against, if any.
+    pub answers: Answers,
+}
+
+/// How to run and report a list of tasks.
+pub struct Options {
+    pub format: Format,
+    /// Compare answers to the recorded ones.
+    pub check: bool,
+    /// Prefix text output with each task's label and part.
+    pub labels: bool,
+    /// How many parts to run at once.
+    pub jobs: usize,
+}
+
+/// Run every part of every task, printing the results in order as they become available.
+pub fn run_tasks(tasks: &[Task], opts: &Options, cache: &mut InputCache) -> Vec<Record> {
+    // each task's input is read once and shared by its parts
+    let inputs: Vec<_> = tasks
+        .iter()
+        .map(|task| cache.read(&task.input).ok())
+        .collect();
+
+    let jobs: Vec<(usize, u8)> = tasks
+        .iter()
+        .enumerate()
+        .flat_map(|(i, task)| task.parts.iter().map(move |&part| (i, part)))
+        .collect();
+
+    let execute = |&(i, part): &(usize, u8)| {
+        let task = &tasks[i];
+        let result = match &inputs[i] {
+            Some(input) => run(task.year, task.day, part, input),
+            None if matches!(task.input, Input::Stdin) => {
+                Err(format!("couldn't read day {}'s input from stdin", task.day))
+            }
+            None => Err(format!(
+                "input file for day {} is missing or unreadable",
+                task.day
+            )),
+        };
+        let check = match &result {
+            Ok(run) if opts.check => Some(task.answers.check(part, &run.answer)),
+            _ => None,
+        };
+        Record {
+            day: task.day,
+            part,
+            input: task.input.name().to_string(),
+            result,
+            check,
+        }
+    };
+
+    if opts.format == Format::Tsv {
+        println!("{}", report::TSV_HEADER);
+    }
+
+    let mut records = Vec::with_capacity(jobs.len());
+
+    if opts.jobs <= 1 {
+        for job in &jobs {
+            // print the label first so slow days show what they're working on
+            print_label(&tasks[job.0], job.1, opts);
+            let record = execute(job);
+            print_record(&record, &tasks[job.0], opts);
+            records.push(record);
+        }
+        return records;
+    }
+
+    let next_job = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..opts.jobs.min(jobs.len()) {
+            let (tx, next_job, jobs, execute) = (tx.clone(), &next_job, &jobs, &execute);
+            scope.spawn(move || loop {
+                let i = next_job.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else {
+                    break;
+                };
+                tx.send((i, execute(job))).unwrap();
+            });
+        }
+        drop(tx);
+
+        // hold on to finished parts until everything before them has been printed
+        let mut finished = BTreeMap::new();
+        for (i, record) in rx {
+            finished.insert(i, record);
+            while let Some(record) = finished.remove(&records.len()) {
+                let (task, part) = jobs[records.len()];
+                print_label(&tasks[task], part, opts);
+                print_record(&record, &tasks[task], opts);
+                records.push(record);
+            }
+        }
+    });
+
+    records
+}
+
+fn print_label(task: &Task, part: u8, opts: &Options) {
+    if opts.format == Format::Text && opts.labels {
+        print!("{} part {part}: ", task.label);
+    }
+}
+
+fn print_record(record: &Record, task: &Task, opts: &Options) {
+    match (&record.result, opts.format) {
+        (Ok(_), Format::Text) => println!(
+            "{}",
+            report::format_text(record, task.answers.get(record.part))
+        ),
+        (Err(_), Format::Text) => {
+            if opts.labels {
+                println!();
+            }
+            eprintln!("{}", report::format_text(record, None));
+        }
+        (_, format) => println!("{}", report::format_record(record, format)),
+    }
+}
+
+/// Run one part of a day, turning errors and panics into messages so one broken day doesn't stop
+/// the rest.
+fn run(year: u16, day: u8, part: u8, input: &str) -> Result<aoc2022::Run, String> {
+    let solution =
+        aoc2022::solution(year, day).ok_or(format!("{year} day {day} isn't implemented"))?;
+
+    catch_unwind(AssertUnwindSafe(|| solution.run(part, input)))
+        .map_err(|panic| {
+            let msg = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            format!("day {day} part {part} panicked: {msg}")
+        })?
+        .map_err(|e| format!("day {day}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_inputs() {
+        let path = std::env::temp_dir().join(format!("aoc2022-cache-{}", std::process::id()));
+        let input = Input::File(path.display().to_string());
+        let mut cache = InputCache::default();
+
+        // a missing file isn't remembered
+        assert!(cache.read(&input).is_err());
+        std::fs::write(&path, "first").unwrap();
+        assert_eq!(cache.read(&input).unwrap(), "first");
+
+        std::fs::write(&path, "second").unwrap();
+        assert_eq!(cache.read(&input).unwrap(), "first");
+        cache.clear();
+        assert_eq!(cache.read(&input).unwrap(), "second");
+
+        assert_eq!(cache.read(&Input::Text("x".into())).unwrap(), "x");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,304 @@
+//! `aoc new <day>`: create a new day's source file from `templates/d.rs`, an empty example file,
+//! and register the day in its year's module (`src/yYYYY.rs`), creating that too if it's the
+//! year's first day.  Anything that already exists is left alone, so it's safe to run more than
+//! once.
+
+use crate::args::NewArgs;
+use std::{fs, io, path::Path};
+
+pub fn new_day(args: NewArgs) -> bool {
+    if !(1..=25).contains(&args.day) {
+        eprintln!("Error: DAY must be 1 through 25");
+        return false;
+    }
+
+    match scaffold(Path::new("."), args.year, args.day, args.force) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("Error: couldn't scaffold day {}: {e}", args.day);
+            false
+        }
+    }
+}
+
+/// Scaffold a day in the project rooted at `root`.
+fn scaffold(root: &Path, year: u16, day: u8, force: bool) -> io::Result<()> {
+    let source = root.join(format!("src/y{year}/d{day}.rs"));
+    if force || !source.exists() {
+        let template = fs::read_to_string(root.join("templates/d.rs"))?;
+        fs::create_dir_all(root.join(format!("src/y{year}")))?;
+        fs::write(&source, render(&template, day, year))?;
+        println!("source created: {}", source.display());
+    } else {
+        println!("source for {day} already exists in {}", source.display());
+    }
+
+    let example = root.join(format!("examples/{year}/d{day}"));
+    if !example.exists() {
+        fs::create_dir_all(root.join(format!("examples/{year}")))?;
+        fs::write(&example, "")?;
+        println!("empty example created: {}", example.display());
+    } else {
+        println!(
+            "example for day {day} already exists in {}",
+            example.display()
+        );
+    }
+
+    let year_path = root.join(format!("src/y{year}.rs"));
+    if !year_path.exists() {
+        fs::write(&year_path, year_module(year))?;
+        println!("module created: {}", year_path.display());
+    }
+    update(&root.join("src/lib.rs"), |lib| {
+        register_year(&register_mod(lib, &format!("y{year}")), year)
+    })?;
+    update(&year_path, |module| {
+        register_day(&register_mod(module, &format!("d{day}")), day)
+    })?;
+
+    Ok(())
+}
+
+/// Rewrite a file, if that changes it.
+fn update(path: &Path, f: impl Fn(&str) -> String) -> io::Result<()> {
+    let text = fs::read_to_string(path)?;
+    let updated = f(&text);
+    if updated != text {
+        fs::write(path, updated)?;
+        println!("registered in {}", path.display());
+    }
+    Ok(())
+}
+
+/// Fill in the template's `$AOC_DAY` and `$AOC_YEAR` variables (either may be written `${...}`).
+fn render(template: &str, day: u8, year: u16) -> String {
+    let (day, year) = (day.to_string(), year.to_string());
+    template
+        .replace("${AOC_DAY}", &day)
+        .replace("$AOC_DAY", &day)
+        .replace("${AOC_YEAR}", &year)
+        .replace("$AOC_YEAR", &year)
+}
+
+/// A new year's module, with no days yet.
+fn year_module(year: u16) -> String {
+    format!(
+        "//! The solutions for {year}.
+
+use crate::DynSolution;
+
+/// Every day's solution, in day order.  Register new days here (`aoc new <day>` does it for you).
+pub static SOLUTIONS: &[&dyn DynSolution] = &[];
+"
+    )
+}
+
+/// Add `pub mod <name>;` to a module, keeping the `pub mod` lines in the order rustfmt sorts them.
+/// If there aren't any yet, it goes before the first static.
+fn register_mod(text: &str, name: &str) -> String {
+    let line = format!("pub mod {name};");
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line.as_str()) {
+        return text.to_string();
+    }
+
+    let is_mod = |l: &&str| l.starts_with("pub mod ");
+    match lines.iter().position(is_mod) {
+        Some(mods) => {
+            let end = lines[mods..]
+                .iter()
+                .position(|l| !is_mod(l))
+                .map_or(lines.len(), |i| mods + i);
+            // compare names rather than whole lines, so "d1;" sorts before "d10;" like rustfmt does
+            fn name_of(line: &str) -> &str {
+                line.trim_start_matches("pub mod ").trim_end_matches(';')
+            }
+            let at = mods + lines[mods..end].partition_point(|l| name_of(l) < name);
+            lines.insert(at, &line);
+        }
+        None => {
+            let mut at = lines
+                .iter()
+                .position(|l| l.starts_with("pub static"))
+                .unwrap_or(lines.len());
+            while at > 0 && lines[at - 1].starts_with("///") {
+                at -= 1;
+            }
+            lines.splice(at..at, [line.as_str(), ""]);
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Add `&dN::DayN` to a year's `SOLUTIONS`, keeping it in day order.
+fn register_day(text: &str, day: u8) -> String {
+    rewrite_static(text, "SOLUTIONS", day, "&d", "::Day", |day| {
+        format!("&d{day}::Day{day}")
+    })
+}
+
+/// Add a year to the `YEARS` registry in lib.rs, keeping it in year order.
+fn register_year(text: &str, year: u16) -> String {
+    rewrite_static(text, "YEARS", year, "y", "::SOLUTIONS", |year| {
+        format!("Year {{\n        year: {year},\n        solutions: y{year}::SOLUTIONS,\n    }}")
+    })
+}
+
+/// Rewrite the array in `pub static <name>` so it holds an entry for `key`, along with the
+/// entries it already had.  Existing entries are found by the number between `before` and
+/// `after` (e.g. the 3 in `&d3::Day3`), and everything's written one entry per line, in order.
+fn rewrite_static<K: Ord + Copy + std::str::FromStr>(
+    text: &str,
+    name: &str,
+    key: K,
+    before: &str,
+    after: &str,
+    entry: impl Fn(K) -> String,
+) -> String {
+    let Some(start) = text.find(&format!("pub static {name}")) else {
+        return text.to_string();
+    };
+    let Some(len) = text[start..].find("];") else {
+        return text.to_string();
+    };
+    let item = &text[start..start + len + 2];
+    let Some(open) = item.find("= &[") else {
+        return text.to_string();
+    };
+
+    let mut keys: Vec<K> = item
+        .split(after)
+        .filter_map(|chunk| chunk.rsplit(before).next()?.parse().ok())
+        .collect();
+    if keys.contains(&key) {
+        return text.to_string();
+    }
+    keys.push(key);
+    keys.sort();
+
+    let mut rewritten = item[..open + 4].to_string();
+    rewritten.push('\n');
+    for key in keys {
+        rewritten.push_str(&format!("    {},\n", entry(key)));
+    }
+    rewritten.push_str("];");
+
+    text.replacen(item, &rewritten, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "\
+#![allow(unused)]
+pub mod answers;
+pub mod error;
+pub mod y2022;
+
+/// Every year's solutions, in year order.
+pub static YEARS: &[Year] = &[Year {
+    year: 2022,
+    solutions: y2022::SOLUTIONS,
+}];
+";
+
+    const YEAR: &str = "\
+//! The solutions for 2022.
+
+use crate::DynSolution;
+
+pub mod d1;
+pub mod d2;
+
+/// Every day's solution, in day order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &d1::Day1,
+    &d2::Day2,
+];
+";
+
+    #[test]
+    fn render_template() {
+        assert_eq!(
+            render(
+                "day $AOC_DAY year $AOC_YEAR: Day$AOC_DAY, d${AOC_DAY}p1",
+                7,
+                2022
+            ),
+            "day 7 year 2022: Day7, d7p1"
+        );
+    }
+
+    #[test]
+    fn register_days() {
+        let module = register_day(&register_mod(YEAR, "d10"), 10);
+        assert!(module.contains("pub mod d1;\npub mod d10;\npub mod d2;\n"));
+        assert!(module.contains("    &d2::Day2,\n    &d10::Day10,\n];"));
+
+        let module = register_day(&register_mod(&module, "d3"), 3);
+        assert!(module.contains("pub mod d2;\npub mod d3;\n\n"));
+        assert!(module.contains("    &d2::Day2,\n    &d3::Day3,\n    &d10::Day10,\n"));
+
+        // registering again changes nothing
+        assert_eq!(register_day(&register_mod(&module, "d3"), 3), module);
+
+        // a new year's first day
+        let module = register_day(&register_mod(&year_module(2023), "d1"), 1);
+        assert!(module.contains("use crate::DynSolution;\n\npub mod d1;\n\n/// Every day"));
+        assert!(module.contains("= &[\n    &d1::Day1,\n];"));
+    }
+
+    #[test]
+    fn register_years() {
+        let lib = register_year(&register_mod(LIB, "y2021"), 2021);
+        assert!(lib.contains("pub mod error;\npub mod y2021;\npub mod y2022;\n"));
+        assert!(lib.contains(
+            "= &[
+    Year {
+        year: 2021,
+        solutions: y2021::SOLUTIONS,
+    },
+    Year {
+        year: 2022,
+        solutions: y2022::SOLUTIONS,
+    },
+];"
+        ));
+        assert_eq!(register_year(&register_mod(LIB, "y2022"), 2022), LIB);
+    }
+
+    #[test]
+    fn scaffold_is_idempotent() {
+        let root = std::env::temp_dir().join(format!("aoc2022-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("templates")).unwrap();
+        fs::write(root.join("templates/d.rs"), "// day $AOC_DAY\n").unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/y2022.rs"), YEAR).unwrap();
+
+        scaffold(&root, 2022, 3, false).unwrap();
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(read("src/y2022/d3.rs"), "// day 3\n");
+        assert_eq!(read("examples/2022/d3"), "");
+        assert_eq!(read("src/lib.rs"), LIB);
+        let module = read("src/y2022.rs");
+        assert!(module.contains("pub mod d3;"));
+
+        // a second run keeps the work done since
+        fs::write(root.join("src/y2022/d3.rs"), "// solved").unwrap();
+        scaffold(&root, 2022, 3, false).unwrap();
+        assert_eq!(read("src/y2022/d3.rs"), "// solved");
+        assert_eq!(read("src/y2022.rs"), module);
+
+        // a new year gets its own module
+        scaffold(&root, 2023, 1, false).unwrap();
+        assert!(read("src/lib.rs").contains("pub mod y2023;"));
+        assert!(read("src/lib.rs").contains("solutions: y2023::SOLUTIONS,"));
+        assert!(read("src/y2023.rs").contains("&d1::Day1,"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+//! The interface shared by every day's solution, so the runner can treat all days the same way.
+
+use crate::error::{AocError, Result};
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// A solution to one day's puzzle.
+///
+/// Each `yYYYY::dN` module implements this on a unit struct (`dN::DayN`) by forwarding to its own
+/// `parse`, `part1` and `part2` functions.  Bad input should be reported as an [`AocError`] rather
+/// than a panic, though the runner copes with panics too.
+pub trait Solution {
+    /// The day of the month this solution solves.
+    const DAY: u8;
+
+    /// The parsed puzzle input.  The lifetime allows models that borrow from the input text.
+    type Model<'input>;
+
+    /// The answer produced by both parts.
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>>;
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer>;
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer>;
+}
+
+/// An object-safe view of a [`Solution`].  Every `Solution` gets this for free, which lets days
+/// with different model and answer types share one registry.
+pub trait DynSolution: Sync {
+    /// The day of the month this solution solves.
+    fn day(&self) -> u8;
+
+    /// Parse `input` and solve the given part (1 or 2), timing each phase.
+    fn run(&self, part: u8, input: &str) -> Result<Run>;
+}
+
+/// The outcome of running one part of one day.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u8,
+    pub part: u8,
+    /// The answer, formatted for display.
+    pub answer: String,
+    /// Time spent in the day's `parse`.  This includes any copy of the input the day's
+    /// [`Solution::parse`] makes on the way in, since most days' own parsers take a `String`.
+    pub parse_time: Duration,
+    /// Time spent in the day's `part1` or `part2`.
+    pub solve_time: Duration,
+}
+
+impl Run {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.solve_time
+    }
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, part: u8, input: &str) -> Result<Run> {
+        let start = Instant::now();
+        let model = S::parse(input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(model)?.to_string(),
+            2 => S::part2(model)?.to_string(),
+            _ => panic!("part must be 1 or 2"),
+        };
+        let solve_time = start.elapsed();
+
+        Ok(Run {
+            day: S::DAY,
+            part,
+            answer,
+            parse_time,
+            solve_time,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{solution, solutions, YEARS};
+
+    #[test]
+    fn registry_is_in_order() {
+        for years in YEARS.windows(2) {
+            assert!(years[0].year < years[1].year);
+        }
+        for year in YEARS {
+            for days in year.solutions.windows(2) {
+                assert!(days[0].day() < days[1].day());
+            }
+        }
+        // 2022 is complete
+        for (i, solution) in solutions(2022).unwrap().iter().enumerate() {
+            assert_eq!(solution.day() as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn registry_runs_a_day() {
+        let ex = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+        assert_eq!(solution(2022, 4).unwrap().run(1, ex).unwrap().answer, "2");
+        assert_eq!(solution(2022, 4).unwrap().run(2, ex).unwrap().answer, "4");
+        assert!(solution(2022, 26).is_none());
+        assert!(solution(1999, 4).is_none());
+    }
+
+    #[test]
+    fn registry_reports_bad_input() {
+        let err = solution(2022, 7)
+            .unwrap()
+            .run(
+                1,
+                "$ ls
+$ cf a",
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 1: expected `$ cd`, `$ ls`, `dir` or a file"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! A sparse, unbounded grid, for simulations that spread out in every direction (sand piling up,
+//! elves wandering off, a rope's tail).
+//!
+//! Only the occupied cells are stored.  The bounding box of those cells is kept as they come and
+//! go: inserting only ever grows it, and removing a cell from its edge leaves it to be worked out
+//! again the next time it's asked for.
+
+use crate::geom::{Bounds, Point};
+use std::{cell::Cell, collections::HashMap};
+
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    /// The bounds of `cells`, unless they need working out again.
+    bounds: Cell<Option<Bounds>>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: Cell::new(None),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Put a value at a point, returning the one that was there.
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        if let Some(mut bounds) = self.bounds.get() {
+            bounds.include(p);
+            self.bounds.set(Some(bounds));
+        }
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        let value = self.cells.remove(&p)?;
+        if let Some(Bounds { min, max }) = self.bounds.get() {
+            if p.x == min.x || p.y == min.y || p.x == max.x || p.y == max.y {
+                self.bounds.set(None);
+            }
+        }
+        Some(value)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// Every occupied point and its value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, value)| (p, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The smallest rectangle around every occupied point, unless there aren't any.
+    pub fn bounds(&self) -> Option<Bounds> {
+        if self.bounds.get().is_none() {
+            self.bounds.set(Bounds::of(self.points()));
+        }
+        self.bounds.get()
+    }
+
+    /// The occupied points next to `p`, in [`crate::geom::Dir::ALL`] order.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        p.neighbours4()
+            .filter_map(|q| self.get(q).map(|value| (q, value)))
+    }
+
+    /// The occupied points around `p`, clockwise from the one above.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        p.neighbours8()
+            .filter_map(|q| self.get(q).map(|value| (q, value)))
+    }
+
+    /// Draw the occupied region, with `pad` cells of margin all around, as text: one character
+    /// per cell (`None` for the empty ones) and a newline after every row.  An empty grid draws
+    /// nothing.
+    pub fn render(&self, pad: i32, mut f: impl FnMut(Option<&T>) -> char) -> String {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+        let mut out = String::new();
+        for y in (bounds.min.y - pad)..=(bounds.max.y + pad) {
+            for x in (bounds.min.x - pad)..=(bounds.max.x + pad) {
+                out.push(f(self.get(Point::new(x, y))));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (p, value) in iter {
+            self.insert(p, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_remove() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.insert(Point::new(2, 3), 'a'), None);
+        assert_eq!(grid.insert(Point::new(2, 3), 'b'), Some('a'));
+        grid.insert(Point::new(-4, 0), 'c');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(Point::new(2, 3)), Some(&'b'));
+        assert!(!grid.contains(Point::new(0, 0)));
+
+        *grid.get_mut(Point::new(-4, 0)).unwrap() = 'd';
+        assert_eq!(grid.remove(Point::new(-4, 0)), Some('d'));
+        assert_eq!(grid.remove(Point::new(-4, 0)), None);
+        assert_eq!(grid.points().collect::<Vec<_>>(), [Point::new(2, 3)]);
+    }
+
+    #[test]
+    fn bounds_follow_the_cells() {
+        let mut grid: SparseGrid<()> = [Point::new(0, 0), Point::new(5, -2)]
+            .into_iter()
+            .map(|p| (p, ()))
+            .collect();
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (bounds.min, bounds.max),
+            (Point::new(0, -2), Point::new(5, 0))
+        );
+
+        // growing keeps the box up to date, and shrinking works it out again
+        grid.insert(Point::new(7, 1), ());
+        assert_eq!(grid.bounds().unwrap().max, Point::new(7, 1));
+        grid.remove(Point::new(7, 1));
+        grid.remove(Point::new(0, 0));
+        assert_eq!(grid.bounds(), Some(Bounds::new(Point::new(5, -2))));
+        grid.remove(Point::new(5, -2));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn neighbours_and_rendering() {
+        let grid: SparseGrid<char> = [(0, 0, 'a'), (1, 0, 'b'), (1, 1, 'c'), (-1, -1, 'd')]
+            .into_iter()
+            .map(|(x, y, c)| (Point::new(x, y), c))
+            .collect();
+        let near: Vec<_> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(near, [(Point::new(1, 0), &'b')]);
+        let around: String = grid
+            .neighbours8(Point::new(0, 0))
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(around, "bcd");
+
+        assert_eq!(grid.render(0, |c| *c.unwrap_or(&'.')), "d..\n.ab\n..c\n");
+        assert_eq!(
+            grid.render(1, |c| if c.is_some() { '#' } else { '.' })
+                .lines()
+                .count(),
+            5
+        );
+        assert_eq!(SparseGrid::<char>::new().render(2, |_| '#'), "");
+    }
+}
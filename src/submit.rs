@@ -0,0 +1,403 @@
+//! `aoc submit`: send a day's answer to the puzzle site, and keep a ledger of every attempt.
+//!
+//! The ledger (`ledger.json` by default) is checked before anything is sent, so an answer that's
+//! already been rejected, or that's outside a too-high/too-low bound, isn't submitted again.
+
+use crate::{args::SubmitArgs, config, fetch::DEFAULT_URL};
+use aoc2022::paths;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs,
+    io::ErrorKind,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently.  The wait is in seconds, if the site said.
+    Wait(Option<u64>),
+    /// The part has already been solved (or part 1 hasn't been yet).
+    WrongLevel,
+    /// A response that didn't look like any of the above.
+    Unknown,
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Every answer submitted so far.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    pub attempts: Vec<Attempt>,
+}
+
+/// Everything needed to submit one answer.
+struct Request<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    url: &'a str,
+    session: Option<&'a str>,
+}
+
+impl Ledger {
+    /// Read the ledger.  A missing file is an empty ledger, but an unreadable one is an error,
+    /// so the attempts in it don't get overwritten.
+    pub fn load(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => {
+                serde_json::from_str(&json).map_err(|e| format!("couldn't read {path}: {e}"))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("couldn't read {path}: {e}")),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("couldn't serialize ledger");
+        fs::write(path, json)
+    }
+
+    /// The reason an answer shouldn't be submitted, judging by the earlier attempts at the same
+    /// puzzle, if there is one.
+    pub fn refusal(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<String> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |a| a.year == year && a.day == day && a.part == part)
+        };
+
+        if let Some(right) = attempts().find(|a| a.outcome == Outcome::Right) {
+            return Some(format!("already solved with {}", right.answer));
+        }
+        if let Some(known) = attempts().find(|a| a.answer == answer && a.outcome.is_wrong()) {
+            return Some(format!("{answer} was already submitted: {}", known.outcome));
+        }
+
+        let number: i128 = answer.parse().ok()?;
+        let bound = |outcome: Outcome| {
+            attempts()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Outcome::TooHigh).filter(|&high| number >= high).min() {
+            return Some(format!("{answer} is too high, since {high} was"));
+        }
+        if let Some(low) = bound(Outcome::TooLow).filter(|&low| number <= low).max() {
+            return Some(format!("{answer} is too low, since {low} was"));
+        }
+
+        None
+    }
+}
+
+impl Outcome {
+    /// Read the outcome from the page the site responds with.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Outcome::Right
+        } else if page.contains("too recently") {
+            Outcome::Wait(parse_wait(page))
+        } else if page.contains("solving the right level") {
+            Outcome::WrongLevel
+        } else if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("not the right answer") {
+            Outcome::Wrong
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    /// Whether the answer is known to be wrong, so there's no point submitting it again.
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "wrong (too high)"),
+            Outcome::TooLow => write!(f, "wrong (too low)"),
+            Outcome::Wait(Some(secs)) => write!(f, "submitted too recently, wait {secs}s"),
+            Outcome::Wait(None) => write!(f, "submitted too recently"),
+            Outcome::WrongLevel => write!(f, "not the right level; is it already solved?"),
+            Outcome::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// Read the wait from e.g. "You have 1m 5s left to wait." as a number of seconds.
+fn parse_wait(page: &str) -> Option<u64> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let wait = before.rsplit("You have ").next()?;
+    wait.split_whitespace().try_fold(0, |secs, part| {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        match unit {
+            "s" => Some(secs + n),
+            "m" => Some(secs + n * 60),
+            "h" => Some(secs + n * 3600),
+            _ => None,
+        }
+    })
+}
+
+pub fn submit(args: SubmitArgs) -> bool {
+    if !(1..=25).contains(&args.day) || ![1, 2].contains(&args.part) {
+        eprintln!("Error: DAY must be 1 through 25, and part must be 1 or 2");
+        return false;
+    }
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => match solve(args.year, args.day, args.part) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return false;
+            }
+        },
+    };
+    let url = args
+        .url
+        .or_else(|| config::var("AOC_URL"))
+        .unwrap_or_else(|| DEFAULT_URL.to_string());
+    let session = config::var("AOC_SESSION");
+    let req = Request {
+        year: args.year,
+        day: args.day,
+        part: args.part,
+        answer: &answer,
+        url: url.trim_end_matches('/'),
+        session: session.as_deref(),
+    };
+
+    let mut ledger = match Ledger::load(&args.ledger) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return false;
+        }
+    };
+    if let Some(reason) = ledger.refusal(req.year, req.day, req.part, &answer) {
+        eprintln!("Not submitting: {reason}");
+        return false;
+    }
+
+    println!("day {} part {}: submitting {answer}", args.day, args.part);
+    let attempt = match send(&req) {
+        Ok(attempt) => attempt,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return false;
+        }
+    };
+    println!("{}", attempt.outcome);
+
+    let right = attempt.outcome == Outcome::Right;
+    ledger.attempts.push(attempt);
+    if let Err(e) = ledger.save(&args.ledger) {
+        eprintln!("Error: couldn't write {}: {e}", args.ledger);
+    }
+
+    right
+}
+
+/// Solve a day's part with its puzzle input.
+fn solve(year: u16, day: u8, part: u8) -> Result<String, String> {
+    let input = fs::read_to_string(paths::input(year, day))
+        .map_err(|_| format!("input file for day {day} is missing or unreadable"))?;
+    let solution =
+        aoc2022::solution(year, day).ok_or(format!("{year} day {day} isn't implemented"))?;
+    let run = solution
+        .run(part, &input)
+        .map_err(|e| format!("day {day}: {e}"))?;
+    Ok(run.answer.trim().to_string())
+}
+
+/// Post an answer to the site.
+fn send(req: &Request) -> Result<Attempt, String> {
+    let url = format!("{}/{}/day/{}/answer", req.url, req.year, req.day);
+
+    let mut request = ureq::post(&url).set("User-Agent", crate::fetch::USER_AGENT);
+    match req.session {
+        Some(session) => request = request.set("Cookie", &format!("session={session}")),
+        None if req.url == DEFAULT_URL => return Err("please set AOC_SESSION".into()),
+        None => {}
+    }
+
+    let level = req.part.to_string();
+    let page = match request.send_form(&[("level", &level), ("answer", req.answer)]) {
+        Ok(response) => response.into_string(),
+        Err(ureq::Error::Status(status, _)) => return Err(format!("got HTTP {status}")),
+        Err(e) => return Err(format!("request to {url} failed: {e}")),
+    }
+    .map_err(|e| format!("couldn't read response: {e}"))?;
+
+    Ok(Attempt {
+        year: req.year,
+        day: req.day,
+        part: req.part,
+        answer: req.answer.to_string(),
+        outcome: Outcome::parse(&page),
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::serve;
+
+    fn attempt(part: u8, answer: &str, outcome: Outcome) -> Attempt {
+        Attempt {
+            year: 2022,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn parse_outcomes() {
+        let page = |text| format!("<html><main><article><p>{text}</p></article></main></html>");
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer!  You are one gold star closer."
+            )),
+            Outcome::Right
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer.  If you're stuck...")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait."
+            )),
+            Outcome::Wait(Some(65))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(Outcome::parse("?"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn refuse_known_answers() {
+        let ledger = Ledger {
+            attempts: vec![
+                attempt(1, "500", Outcome::TooHigh),
+                attempt(1, "900", Outcome::TooHigh),
+                attempt(1, "100", Outcome::TooLow),
+                attempt(1, "250", Outcome::Wrong),
+                attempt(1, "260", Outcome::Wait(Some(30))),
+                attempt(2, "7", Outcome::Right),
+            ],
+        };
+        let refusal = |part, answer| ledger.refusal(2022, 1, part, answer);
+
+        assert_eq!(refusal(1, "300"), None);
+        assert_eq!(refusal(1, "260"), None);
+        assert_eq!(
+            refusal(1, "250"),
+            Some("250 was already submitted: wrong".into())
+        );
+        assert_eq!(
+            refusal(1, "600"),
+            Some("600 is too high, since 500 was".into())
+        );
+        assert_eq!(
+            refusal(1, "99"),
+            Some("99 is too low, since 100 was".into())
+        );
+        assert_eq!(refusal(2, "8"), Some("already solved with 7".into()));
+        assert_eq!(ledger.refusal(2021, 1, 1, "600"), None);
+    }
+
+    #[test]
+    fn ledger_files() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-submit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+
+        let empty = Ledger::load(&path("missing.json")).unwrap();
+        assert!(empty.attempts.is_empty());
+        fs::write(path("corrupt.json"), "{\"attempts\": [").unwrap();
+        assert!(Ledger::load(&path("corrupt.json")).is_err());
+        fs::write(path("binary.json"), [0xff, 0xfe]).unwrap();
+        assert!(Ledger::load(&path("binary.json")).is_err());
+        // a directory can't be read, but it isn't missing either
+        assert!(Ledger::load(dir.to_str().unwrap()).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn submit_to_mock_server() {
+        let (url, requests) = serve(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+        let req = Request {
+            year: 2022,
+            day: 1,
+            part: 2,
+            answer: "1234",
+            url: &url,
+            session: Some("abc"),
+        };
+
+        let attempt = send(&req).unwrap();
+        assert_eq!(attempt.outcome, Outcome::TooLow);
+        assert_eq!(attempt.answer, "1234");
+
+        let request = requests.lock().unwrap()[0].clone();
+        assert!(request.starts_with("POST /2022/day/1/answer "));
+        assert!(request.contains("session=abc"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+}
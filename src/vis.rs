@@ -0,0 +1,515 @@
+//! Animations of a day's progress, for the days that have them (d5, d17, d22, d23 and d24).
+//!
+//! Days draw through the [`Visualizer`] trait, and [`open`] picks the backend: a recording (after
+//! [`record`]), the terminal (with `--features visualize`), or nothing at all.  [`Recorder`] keeps
+//! every frame in memory instead, so animations can be tested and exported without a terminal.
+//!
+//! Some days (d9, d12, d14 and d18) can also save their final state as an SVG picture, through
+//! [`snapshot`].
+
+pub mod cast;
+pub mod image;
+pub mod playback;
+pub mod svg;
+
+use self::image::{ImageFormat, Images};
+use cast::Cast;
+use std::{
+    fmt::Display,
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+use svg::Svg;
+
+/// The colors a day can draw in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Color {
+    /// The terminal's own foreground color.
+    #[default]
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Color {
+    pub const ALL: [Color; 9] = [
+        Color::Default,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+        Color::Grey,
+    ];
+
+    /// Roughly how a terminal shows the color.
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Color::Default => Rgb(204, 204, 204),
+            Color::Red => Rgb(205, 49, 49),
+            Color::Green => Rgb(13, 188, 121),
+            Color::Yellow => Rgb(229, 229, 16),
+            Color::Blue => Rgb(36, 114, 200),
+            Color::Magenta => Rgb(188, 63, 188),
+            Color::Cyan => Rgb(17, 168, 205),
+            Color::White => Rgb(255, 255, 255),
+            Color::Grey => Rgb(128, 128, 128),
+        }
+    }
+}
+
+/// A color for image output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// As `#rrggbb`.
+impl Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Something a day can draw its frames on.
+pub trait Visualizer {
+    /// Whether frames go anywhere.  Days can check this to skip building frames nobody will see.
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    /// Start a new, blank frame.
+    fn begin_frame(&mut self);
+
+    /// Draw one character.  Anything outside the frame is cut off.
+    fn draw_char(&mut self, x: i32, y: i32, c: char, color: Color);
+
+    /// Draw a string starting at x,y.  Each line after the first starts one row further down, at
+    /// the same x.
+    fn draw_str(&mut self, x: i32, y: i32, s: &str, color: Color) {
+        for (dy, line) in s.lines().enumerate() {
+            for (dx, c) in line.chars().enumerate() {
+                self.draw_char(x + dx as i32, y + dy as i32, c, color);
+            }
+        }
+    }
+
+    /// A line about the frame, like which round it shows.  The terminal backend puts it in its
+    /// status bar.
+    fn status(&mut self, status: &str) {}
+
+    /// Finish the frame and show it.  The terminal backend also waits for the frame's turn here.
+    fn end_frame(&mut self);
+
+    /// Keep the last frame up for a while longer, like at the end of an animation.
+    fn sleep(&mut self, duration: Duration) {}
+
+    /// Whether the viewer asked to stop the animation (the terminal backend's `q` key).
+    fn quit(&mut self) -> bool {
+        false
+    }
+
+    /// The color to fill each character's cell with, for backends that draw images.  Characters
+    /// that aren't listed are left as background.
+    fn palette(&mut self, palette: &[(char, Rgb)]) {}
+}
+
+/// Where to record animations instead of showing them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recording {
+    /// An asciicast file.
+    Cast(PathBuf),
+    /// A directory of PNG frames, or an animated GIF.
+    Images(PathBuf, ImageFormat),
+}
+
+/// Where animations are being recorded, if they are, and how many have been so far.
+static RECORDING: Mutex<Option<(Recording, usize)>> = Mutex::new(None);
+
+/// Record every animation from now on, rather than showing it.  If more than one runs, the later
+/// ones get `-2`, `-3` and so on added to their file names.
+pub fn record(recording: Recording) {
+    *RECORDING.lock().unwrap() = Some((recording, 0));
+}
+
+/// Where the next recording goes, and which one it is, if animations are being recorded.
+fn next_recording() -> Option<(Recording, usize)> {
+    let mut recording = RECORDING.lock().unwrap();
+    let (to, count) = recording.as_mut()?;
+    *count += 1;
+    Some((to.clone(), *count))
+}
+
+/// `path`, with `-n` added to its name for every animation after the first.
+fn numbered(path: &Path, n: usize) -> PathBuf {
+    if n <= 1 {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!("{stem}-{n}.{}", ext.to_string_lossy()),
+        None => format!("{stem}-{n}"),
+    };
+    path.with_file_name(name)
+}
+
+/// Where snapshots are being saved, if they are, and how many have been so far.
+static SNAPSHOTS: Mutex<Option<(PathBuf, usize)>> = Mutex::new(None);
+
+/// Save every snapshot from now on as an SVG file at `path`.  If more than one is taken, the later
+/// ones get `-2`, `-3` and so on added to their names.
+pub fn snapshot_to(path: impl Into<PathBuf>) {
+    *SNAPSHOTS.lock().unwrap() = Some((path.into(), 0));
+}
+
+/// Save a picture of a day's state, if [`snapshot_to`] was called.  The picture is only drawn
+/// when it's going to be saved.
+pub fn snapshot(draw: impl FnOnce() -> Svg) {
+    let path = {
+        let mut snapshots = SNAPSHOTS.lock().unwrap();
+        let Some((path, count)) = snapshots.as_mut() else {
+            return;
+        };
+        *count += 1;
+        numbered(path, *count)
+    };
+    if let Err(e) = draw().save(&path) {
+        eprintln!("Error: couldn't save {}: {e}", path.display());
+    }
+}
+
+/// The backend for a day's animation: a recording if [`record`] was called, the terminal when the
+/// `visualize` feature is on, otherwise one that shows nothing.  `width` and `height` are in
+/// characters.
+pub fn open(width: u32, height: u32, fps: u32) -> Box<dyn Visualizer> {
+    let recorder: Option<std::io::Result<Box<dyn Visualizer>>> = match next_recording() {
+        Some((Recording::Cast(path), n)) => Some(
+            File::create(numbered(&path, n))
+                .and_then(|file| Cast::new(BufWriter::new(file), width, height, fps))
+                .map(|cast| Box::new(cast) as _),
+        ),
+        Some((Recording::Images(dir, format), n)) => Some(
+            Images::new(&dir, n, format, width, height, fps).map(|images| Box::new(images) as _),
+        ),
+        None => None,
+    };
+    match recorder {
+        Some(Ok(recorder)) => return recorder,
+        Some(Err(e)) => {
+            eprintln!("Error: couldn't start recording: {e}");
+            return Box::new(Off);
+        }
+        None => {}
+    }
+
+    #[cfg(feature = "visualize")]
+    match Console::new(width, height, fps) {
+        Ok(console) => return Box::new(console),
+        Err(e) => eprintln!("Error: couldn't start the visualizer: {e}"),
+    }
+
+    Box::new(Off)
+}
+
+/// A visualizer that shows nothing.
+pub struct Off;
+
+impl Visualizer for Off {
+    fn enabled(&self) -> bool {
+        false
+    }
+    fn begin_frame(&mut self) {}
+    fn draw_char(&mut self, x: i32, y: i32, c: char, color: Color) {}
+    fn end_frame(&mut self) {}
+}
+
+/// Draws in the terminal with `console_engine`, with a status bar under the frame and the
+/// [`playback`] controls.
+#[cfg(feature = "visualize")]
+pub struct Console {
+    engine: console_engine::ConsoleEngine,
+    /// The frame being drawn.
+    frame: Frame,
+    playback: playback::Playback,
+    frame_time: Duration,
+    /// When the last tick ended.
+    tick_start: std::time::Instant,
+}
+
+#[cfg(feature = "visualize")]
+impl Console {
+    pub fn new(width: u32, height: u32, fps: u32) -> std::io::Result<Self> {
+        let frame_time = Duration::from_secs(1) / fps.max(1);
+        let cells = (width * height).max(1) as usize;
+        Ok(Self {
+            // two more rows for the status bar and the keys, which need some room across
+            engine: console_engine::ConsoleEngine::init(
+                width.max(playback::STATUS_WIDTH),
+                height + 2,
+                fps,
+            )?,
+            frame: Frame::new(width, height, frame_time),
+            playback: playback::Playback::new(playback::HISTORY_CELLS / cells),
+            frame_time,
+            tick_start: std::time::Instant::now(),
+        })
+    }
+
+    /// Wait for the next tick, handle any keys pressed, and show the frame that's on screen.
+    fn tick(&mut self) {
+        use console_engine::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+        use playback::Key;
+
+        // keys are read here rather than through the engine, which loses the order they came in
+        // (and that matters for typing a frame number)
+        let deadline = self.tick_start + self.frame_time;
+        loop {
+            let now = std::time::Instant::now();
+            if now >= deadline || !event::poll(deadline - now).unwrap_or(false) {
+                break;
+            }
+            let Ok(Event::Key(press)) = event::read() else {
+                continue;
+            };
+            if press.kind != KeyEventKind::Press {
+                continue;
+            }
+            let key = match press.code {
+                KeyCode::Char(' ') => Key::Pause,
+                KeyCode::Right | KeyCode::Char('.') => Key::Forward,
+                KeyCode::Left | KeyCode::Char(',') => Key::Back,
+                KeyCode::Char('+' | '=') => Key::Faster,
+                KeyCode::Char('-') => Key::Slower,
+                KeyCode::Char(c @ '0'..='9') => Key::Digit(c as u8 - b'0'),
+                KeyCode::Char('g') | KeyCode::Enter => Key::Jump,
+                KeyCode::Char('q') | KeyCode::Esc => Key::Quit,
+                _ => continue,
+            };
+            self.playback.key(key);
+        }
+        self.tick_start = std::time::Instant::now();
+
+        self.show();
+    }
+
+    fn show(&mut self) {
+        use console_engine::Color as C;
+
+        self.engine.clear_screen();
+        let Some(frame) = self.playback.frame() else {
+            return;
+        };
+        for (i, &(c, color)) in frame.cells.iter().enumerate() {
+            let (x, y) = (i as u32 % frame.width, i as u32 / frame.width);
+            let fg = match color {
+                Color::Default => C::Reset,
+                Color::Red => C::Red,
+                Color::Green => C::Green,
+                Color::Yellow => C::Yellow,
+                Color::Blue => C::Blue,
+                Color::Magenta => C::Magenta,
+                Color::Cyan => C::Cyan,
+                Color::White => C::White,
+                Color::Grey => C::Grey,
+            };
+            self.engine
+                .set_pxl(x as i32, y as i32, console_engine::pixel::pxl_fg(c, fg));
+        }
+        let height = frame.height as i32;
+        let status = self.playback.status();
+        self.engine.print_fbg(0, height, &status, C::Black, C::Grey);
+        self.engine
+            .print_fbg(0, height + 1, playback::HELP, C::DarkGrey, C::Reset);
+        self.engine.draw();
+    }
+}
+
+#[cfg(feature = "visualize")]
+impl Visualizer for Console {
+    fn begin_frame(&mut self) {
+        self.frame = Frame::new(self.frame.width, self.frame.height, self.frame_time);
+    }
+
+    fn draw_char(&mut self, x: i32, y: i32, c: char, color: Color) {
+        if let Some(i) = self.frame.index(x, y) {
+            self.frame.cells[i] = (c, color);
+        }
+    }
+
+    fn status(&mut self, status: &str) {
+        self.frame.status = status.to_string();
+    }
+
+    /// Shows the frame, then waits for its turn to be over, which takes longer when paused.
+    fn end_frame(&mut self) {
+        if !self.playback.push(self.frame.clone()) {
+            return;
+        }
+        loop {
+            self.tick();
+            if self.playback.tick() {
+                break;
+            }
+        }
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        let mut ticks = duration.as_nanos() / self.frame_time.as_nanos().max(1);
+        while (ticks > 0 || self.playback.paused()) && !self.playback.quit() {
+            self.tick();
+            // stepping forward at the end just carries on
+            if self.playback.tick() && self.playback.paused() {
+                break;
+            }
+            ticks = ticks.saturating_sub(1);
+        }
+    }
+
+    fn quit(&mut self) -> bool {
+        self.playback.quit()
+    }
+}
+
+/// One recorded frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub width: u32,
+    pub height: u32,
+    /// Row by row, blank cells are spaces.
+    cells: Vec<(char, Color)>,
+    /// How long the frame stays up: one frame's worth at the recording's fps, plus any sleeps.
+    pub duration: Duration,
+    /// What the day said about the frame, if anything.
+    pub status: String,
+}
+
+impl Frame {
+    fn new(width: u32, height: u32, duration: Duration) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![(' ', Color::Default); (width * height) as usize],
+            duration,
+            status: String::new(),
+        }
+    }
+
+    /// The character and color at x,y, if that's inside the frame.
+    pub fn get(&self, x: i32, y: i32) -> Option<(char, Color)> {
+        self.index(x, y).map(|i| self.cells[i])
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        let (x, y) = (u32::try_from(x).ok()?, u32::try_from(y).ok()?);
+        (x < self.width && y < self.height).then_some((y * self.width + x) as usize)
+    }
+}
+
+/// The frame as text, without colors or trailing spaces.
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1) as usize) {
+            let line: String = row.iter().map(|&(c, _)| c).collect();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps every frame in memory, and never draws anything.
+pub struct Recorder {
+    width: u32,
+    height: u32,
+    frame_time: Duration,
+    pub frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new(width: u32, height: u32, fps: u32) -> Self {
+        Self {
+            width,
+            height,
+            frame_time: Duration::from_secs(1) / fps.max(1),
+            frames: vec![],
+        }
+    }
+}
+
+impl Visualizer for Recorder {
+    fn begin_frame(&mut self) {
+        let frame = Frame::new(self.width, self.height, self.frame_time);
+        self.frames.push(frame);
+    }
+
+    fn draw_char(&mut self, x: i32, y: i32, c: char, color: Color) {
+        let Some(frame) = self.frames.last_mut() else {
+            return;
+        };
+        if let Some(i) = frame.index(x, y) {
+            frame.cells[i] = (c, color);
+        }
+    }
+
+    fn status(&mut self, status: &str) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.status = status.to_string();
+        }
+    }
+
+    fn end_frame(&mut self) {}
+
+    fn sleep(&mut self, duration: Duration) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.duration += duration;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_frames() {
+        let mut rec = Recorder::new(4, 3, 10);
+        rec.begin_frame();
+        rec.draw_str(1, 0, "ab\ncdef", Color::Red);
+        rec.draw_char(-1, 2, 'x', Color::Default);
+        rec.end_frame();
+        rec.sleep(Duration::from_secs(1));
+
+        rec.begin_frame();
+        rec.draw_char(3, 2, 'z', Color::Blue);
+        rec.end_frame();
+
+        assert_eq!(rec.frames.len(), 2);
+        assert_eq!(rec.frames[0].to_string(), " ab\n cde\n\n");
+        assert_eq!(rec.frames[0].get(2, 1), Some(('d', Color::Red)));
+        assert_eq!(rec.frames[0].get(4, 1), None);
+        assert_eq!(rec.frames[0].duration, Duration::from_millis(1100));
+        assert_eq!(rec.frames[1].to_string(), "\n\n   z\n");
+        assert_eq!(rec.frames[1].duration, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn number_recordings() {
+        assert_eq!(
+            numbered(Path::new("out/anim.cast"), 1),
+            Path::new("out/anim.cast")
+        );
+        assert_eq!(
+            numbered(Path::new("out/anim.cast"), 2),
+            Path::new("out/anim-2.cast")
+        );
+        assert_eq!(numbered(Path::new("anim"), 3), Path::new("anim-3"));
+    }
+}
@@ -0,0 +1,176 @@
+//! Recording animations as asciicast files (asciinema's v2 format), which can be replayed with
+//! `asciinema play` or shared as-is.
+//!
+//! Each frame is written as soon as it's finished, so long animations don't pile up in memory.
+
+use super::{Color, Frame, Visualizer};
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+/// Writes every frame to an asciicast file, timestamped at the animation's fps.
+pub struct Cast<W: Write> {
+    out: Option<W>,
+    frame: Frame,
+    frame_time: Duration,
+    /// When the next frame starts.
+    time: Duration,
+    /// The first write error, reported when the recording is finished.
+    error: Option<io::Error>,
+}
+
+impl<W: Write> Cast<W> {
+    pub fn new(mut out: W, width: u32, height: u32, fps: u32) -> io::Result<Self> {
+        writeln!(
+            out,
+            r#"{{"version": 2, "width": {width}, "height": {height}}}"#
+        )?;
+        let frame_time = Duration::from_secs(1) / fps.max(1);
+        Ok(Self {
+            out: Some(out),
+            frame: Frame::new(width, height, frame_time),
+            frame_time,
+            time: Duration::ZERO,
+            error: None,
+        })
+    }
+
+    fn event(&mut self, data: &str) {
+        let Some(out) = &mut self.out else {
+            return;
+        };
+        let event = serde_json::json!([self.time.as_secs_f64(), "o", data]);
+        if let Err(e) = writeln!(out, "{event}") {
+            self.error.get_or_insert(e);
+        }
+    }
+
+    /// Write the end of the recording (so the last frame stays up for its full time) and hand
+    /// back the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.event("");
+        let mut out = self.out.take().expect("a cast is only finished once");
+        out.flush()?;
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => Ok(out),
+        }
+    }
+}
+
+impl<W: Write> Visualizer for Cast<W> {
+    fn begin_frame(&mut self) {
+        self.frame = Frame::new(self.frame.width, self.frame.height, self.frame_time);
+    }
+
+    fn draw_char(&mut self, x: i32, y: i32, c: char, color: Color) {
+        if let Some(i) = self.frame.index(x, y) {
+            self.frame.cells[i] = (c, color);
+        }
+    }
+
+    fn end_frame(&mut self) {
+        let data = ansi(&self.frame);
+        self.event(&data);
+        self.time += self.frame_time;
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        self.time += duration;
+    }
+}
+
+impl<W: Write> Drop for Cast<W> {
+    fn drop(&mut self) {
+        if self.out.is_some() {
+            self.event("");
+            let flushed = self.out.as_mut().map(|out| out.flush());
+            if let Some(e) = self.error.take().or(flushed.and_then(Result::err)) {
+                eprintln!("Error: couldn't write the recording: {e}");
+            }
+        }
+    }
+}
+
+/// A frame as terminal output: clear the screen, then draw each row with its colors.
+fn ansi(frame: &Frame) -> String {
+    let mut out = String::from("\x1b[H\x1b[2J");
+    let mut current = Color::Default;
+    for (y, row) in frame.cells.chunks(frame.width.max(1) as usize).enumerate() {
+        if y > 0 {
+            out.push_str("\r\n");
+        }
+        let len = row
+            .iter()
+            .rposition(|&(c, _)| c != ' ')
+            .map_or(0, |i| i + 1);
+        for &(c, color) in &row[..len] {
+            if color != current && c != ' ' {
+                out.push_str(&format!("\x1b[{}m", sgr(color)));
+                current = color;
+            }
+            out.push(c);
+        }
+    }
+    if current != Color::Default {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// The ANSI foreground color code for a color.
+fn sgr(color: Color) -> u8 {
+    match color {
+        Color::Default => 39,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::White => 97,
+        Color::Grey => 37,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn write_asciicast() {
+        let mut cast = Cast::new(vec![], 5, 2, 4).unwrap();
+        cast.begin_frame();
+        cast.draw_str(0, 0, "ab", Color::Default);
+        cast.draw_str(1, 1, "cd", Color::Red);
+        cast.end_frame();
+        cast.sleep(Duration::from_secs(1));
+        cast.begin_frame();
+        cast.draw_char(4, 0, 'e', Color::Default);
+        cast.end_frame();
+        let out = String::from_utf8(cast.finish().unwrap()).unwrap();
+        assert!(out.starts_with(r#"{"version": 2, "width": 5, "height": 2}"#));
+
+        let lines: Vec<Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines[0],
+            serde_json::json!({"version": 2, "width": 5, "height": 2})
+        );
+        assert_eq!(
+            lines[1],
+            serde_json::json!([0.0, "o", "\x1b[H\x1b[2Jab\r\n \x1b[31mcd\x1b[0m"])
+        );
+        assert_eq!(
+            lines[2],
+            serde_json::json!([1.25, "o", "\x1b[H\x1b[2J    e\r\n"])
+        );
+        // the last frame stays up for its own frame's worth of time
+        assert_eq!(lines[3], serde_json::json!([1.5, "o", ""]));
+        assert_eq!(lines.len(), 4);
+    }
+}
@@ -0,0 +1,505 @@
+//! Exporting animations as images: a PNG for every frame, or one animated GIF.
+//!
+//! Each character cell becomes a square of pixels, filled with the color the day's palette gives
+//! that character (see [`Visualizer::palette`]).  Both formats are written by hand, so nothing
+//! outside of Rust is needed.
+
+use super::{numbered, Color, Frame, Rgb, Visualizer};
+use flate2::{write::ZlibEncoder, Compression};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+/// How many pixels wide and tall each cell is.
+const CELL: u32 = 4;
+
+/// The color of empty cells, and of characters missing from the palette.
+const BACKGROUND: Rgb = Rgb(0, 0, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Gif,
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "png" => Ok(ImageFormat::Png),
+            "gif" => Ok(ImageFormat::Gif),
+            _ => Err(format!("unknown image format {s}")),
+        }
+    }
+}
+
+/// Writes frames into a directory, as `frame-00000.png` and so on, or as `animation.gif`.  The
+/// `n`th animation in a run gets `-n` added to its names.
+pub struct Images {
+    dir: PathBuf,
+    n: usize,
+    format: ImageFormat,
+    frame: Frame,
+    frame_time: Duration,
+    palette: HashMap<char, Rgb>,
+    /// How many frames have been written.
+    count: usize,
+    gif: Option<Gif<BufWriter<File>>>,
+    /// A finished GIF frame, held back until its full duration is known.
+    pending: Option<Frame>,
+    /// The first error, reported when the export is finished.
+    error: Option<io::Error>,
+}
+
+impl Images {
+    pub fn new(
+        dir: &Path,
+        n: usize,
+        format: ImageFormat,
+        width: u32,
+        height: u32,
+        fps: u32,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let frame_time = Duration::from_secs(1) / fps.max(1);
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            n,
+            format,
+            frame: Frame::new(width, height, frame_time),
+            frame_time,
+            palette: HashMap::new(),
+            count: 0,
+            gif: None,
+            pending: None,
+            error: None,
+        })
+    }
+
+    /// The color of one cell.  Without a palette, cells take the color they were drawn with.
+    fn rgb(&self, c: char, color: Color) -> Rgb {
+        match self.palette.get(&c) {
+            Some(&rgb) => rgb,
+            None if c == ' ' || !self.palette.is_empty() => BACKGROUND,
+            None => color.rgb(),
+        }
+    }
+
+    fn write_png(&mut self, frame: &Frame) -> io::Result<()> {
+        let name = format!(
+            "{}-{:05}.png",
+            numbered(Path::new("frame"), self.n).display(),
+            self.count
+        );
+        let pixels = rasterize(frame, |c, color| self.rgb(c, color));
+        let (width, height) = (frame.width * CELL, frame.height * CELL);
+        fs::write(self.dir.join(name), png(width, height, &pixels)?)
+    }
+
+    fn write_gif(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.gif.is_none() {
+            // every color the frames can use is known up front, so one table does for all of them
+            let mut colors = vec![BACKGROUND];
+            colors.extend(self.palette.values());
+            colors.extend(Color::ALL.iter().map(|color| color.rgb()));
+            let path = self.dir.join(numbered(Path::new("animation.gif"), self.n));
+            let file = BufWriter::new(File::create(path)?);
+            self.gif = Some(Gif::new(
+                file,
+                frame.width * CELL,
+                frame.height * CELL,
+                colors,
+            )?);
+        }
+        let pixels = rasterize(frame, |c, color| self.rgb(c, color));
+        let gif = self.gif.as_mut().unwrap();
+        gif.frame(&pixels, frame.duration)
+    }
+
+    fn finish_frame(&mut self, frame: Frame) {
+        let written = match self.format {
+            ImageFormat::Png => self.write_png(&frame),
+            ImageFormat::Gif => self.write_gif(&frame),
+        };
+        if let Err(e) = written {
+            self.error.get_or_insert(e);
+        }
+        self.count += 1;
+    }
+}
+
+impl Visualizer for Images {
+    fn begin_frame(&mut self) {
+        self.frame = Frame::new(self.frame.width, self.frame.height, self.frame_time);
+    }
+
+    fn draw_char(&mut self, x: i32, y: i32, c: char, color: Color) {
+        if let Some(i) = self.frame.index(x, y) {
+            self.frame.cells[i] = (c, color);
+        }
+    }
+
+    fn end_frame(&mut self) {
+        let frame = self.frame.clone();
+        match self.format {
+            ImageFormat::Png => self.finish_frame(frame),
+            ImageFormat::Gif => {
+                if let Some(last) = self.pending.replace(frame) {
+                    self.finish_frame(last);
+                }
+            }
+        }
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        if let Some(frame) = &mut self.pending {
+            frame.duration += duration;
+        }
+    }
+
+    fn palette(&mut self, palette: &[(char, Rgb)]) {
+        self.palette = palette.iter().copied().collect();
+    }
+}
+
+impl Drop for Images {
+    fn drop(&mut self) {
+        if let Some(last) = self.pending.take() {
+            self.finish_frame(last);
+        }
+        if let Some(gif) = self.gif.take() {
+            if let Err(e) = gif.finish() {
+                self.error.get_or_insert(e);
+            }
+        }
+        if let Some(e) = &self.error {
+            eprintln!(
+                "Error: couldn't write images to {}: {e}",
+                self.dir.display()
+            );
+        }
+    }
+}
+
+/// A frame's pixels, row by row.
+fn rasterize(frame: &Frame, rgb: impl Fn(char, Color) -> Rgb) -> Vec<Rgb> {
+    let width = (frame.width * CELL) as usize;
+    let mut pixels = Vec::with_capacity(width * (frame.height * CELL) as usize);
+    for row in frame.cells.chunks(frame.width.max(1) as usize) {
+        let line: Vec<Rgb> = row
+            .iter()
+            .flat_map(|&(c, color)| [rgb(c, color); CELL as usize])
+            .collect();
+        for _ in 0..CELL {
+            pixels.extend(&line);
+        }
+    }
+    pixels
+}
+
+/// Encode an RGB image as a PNG.
+fn png(width: u32, height: u32, pixels: &[Rgb]) -> io::Result<Vec<u8>> {
+    fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        out.extend((data.len() as u32).to_be_bytes());
+        out.extend(kind);
+        out.extend(data);
+        let mut crc = crc32fast::Hasher::new();
+        crc.update(kind);
+        crc.update(data);
+        out.extend(crc.finalize().to_be_bytes());
+    }
+
+    let mut header = vec![];
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // 8 bits per channel, RGB, default compression, filtering and no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut data = ZlibEncoder::new(vec![], Compression::default());
+    for row in pixels.chunks(width.max(1) as usize) {
+        // each row starts with its filter type, which is always "none" here
+        data.write_all(&[0])?;
+        for &Rgb(r, g, b) in row {
+            data.write_all(&[r, g, b])?;
+        }
+    }
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut out, b"IHDR", &header);
+    chunk(&mut out, b"IDAT", &data.finish()?);
+    chunk(&mut out, b"IEND", &[]);
+    Ok(out)
+}
+
+/// An animated GIF, written a frame at a time, that loops forever.
+struct Gif<W: Write> {
+    out: W,
+    width: u16,
+    height: u16,
+    colors: Vec<Rgb>,
+    /// How many bits a color index takes, as the table's size is a power of two.
+    bits: u8,
+}
+
+impl<W: Write> Gif<W> {
+    fn new(mut out: W, width: u32, height: u32, mut colors: Vec<Rgb>) -> io::Result<Self> {
+        let too_big = |_| io::Error::new(io::ErrorKind::InvalidInput, "image too big for a GIF");
+        let (width, height) = (
+            u16::try_from(width).map_err(too_big)?,
+            u16::try_from(height).map_err(too_big)?,
+        );
+        let mut seen = HashSet::new();
+        colors.retain(|&rgb| seen.insert(rgb));
+        colors.truncate(256);
+        let bits = (colors.len().max(2) as u32)
+            .next_power_of_two()
+            .trailing_zeros() as u8;
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        // a global color table of 2^bits colors, with 8 bits per primary
+        out.write_all(&[0xf0 | (bits - 1), 0, 0])?;
+        for i in 0..1 << bits {
+            let Rgb(r, g, b) = colors.get(i).copied().unwrap_or(BACKGROUND);
+            out.write_all(&[r, g, b])?;
+        }
+        // loop forever
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        Ok(Self {
+            out,
+            width,
+            height,
+            colors,
+            bits,
+        })
+    }
+
+    fn frame(&mut self, pixels: &[Rgb], duration: Duration) -> io::Result<()> {
+        let index: HashMap<Rgb, u8> = self
+            .colors
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, &rgb)| (rgb, i as u8))
+            .collect();
+        let indices: Vec<u8> = pixels
+            .iter()
+            .map(|rgb| index.get(rgb).copied().unwrap_or(0))
+            .collect();
+
+        // the frame's delay, in hundredths of a second
+        let delay = (duration.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+        self.out.write_all(&[0x21, 0xf9, 4, 0])?;
+        self.out.write_all(&delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0])?;
+
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&self.width.to_le_bytes())?;
+        self.out.write_all(&self.height.to_le_bytes())?;
+        self.out.write_all(&[0])?;
+
+        let min_code_size = self.bits.max(2);
+        self.out.write_all(&[min_code_size])?;
+        for block in lzw(&indices, min_code_size).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0])
+    }
+
+    fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// Compress color indices with GIF's flavor of LZW.
+fn lzw(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut out = vec![];
+    let (mut bit_buf, mut bit_count) = (0u32, 0u32);
+    let mut emit = |code: u16, size: u32, out: &mut Vec<u8>| {
+        bit_buf |= (code as u32) << bit_count;
+        bit_count += size;
+        while bit_count >= 8 {
+            out.push(bit_buf as u8);
+            bit_buf >>= 8;
+            bit_count -= 8;
+        }
+    };
+
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut size = min_code_size as u32 + 1;
+    let mut next = end + 1;
+    emit(clear, size, &mut out);
+
+    let mut iter = indices.iter();
+    let Some(&first) = iter.next() else {
+        emit(end, size, &mut out);
+        emit(0, 7, &mut out);
+        return out;
+    };
+    let mut prefix = first as u16;
+    for &k in iter {
+        if let Some(&code) = table.get(&(prefix, k)) {
+            prefix = code;
+            continue;
+        }
+        emit(prefix, size, &mut out);
+        if next < 4096 {
+            table.insert((prefix, k), next);
+            next += 1;
+            if next > 1 << size && size < 12 {
+                size += 1;
+            }
+        } else {
+            // the table's full, so start over
+            emit(clear, size, &mut out);
+            table.clear();
+            size = min_code_size as u32 + 1;
+            next = end + 1;
+        }
+        prefix = k as u16;
+    }
+    emit(prefix, size, &mut out);
+    emit(end, size, &mut out);
+    // flush the last partial byte
+    emit(0, 7, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+
+    /// Undo [`lzw`], the way a GIF decoder would.
+    fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1u16 << min_code_size;
+        let end = clear + 1;
+        let mut table: Vec<Vec<u8>> = vec![];
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            *table = (0..clear).map(|i| vec![i as u8]).collect();
+            table.extend([vec![], vec![]]);
+        };
+        reset(&mut table);
+
+        let mut size = min_code_size as u32 + 1;
+        let (mut pos, mut out, mut prev): (u32, Vec<u8>, Option<Vec<u8>>) = (0, vec![], None);
+        loop {
+            let mut code = 0u16;
+            for bit in 0..size {
+                let byte = data[((pos + bit) / 8) as usize];
+                code |= (((byte >> ((pos + bit) % 8)) & 1) as u16) << bit;
+            }
+            pos += size;
+
+            if code == clear {
+                reset(&mut table);
+                size = min_code_size as u32 + 1;
+                prev = None;
+                continue;
+            }
+            if code == end {
+                return out;
+            }
+            let entry = match (table.get(code as usize), &prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) => [prev.clone(), vec![prev[0]]].concat(),
+                (None, None) => panic!("bad code {code}"),
+            };
+            out.extend(&entry);
+            if let Some(prev) = prev {
+                if table.len() < 4096 {
+                    table.push([prev, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() == 1 << size && size < 12 {
+                size += 1;
+            }
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let patterned: Vec<u8> = (0..20_000u32).map(|i| ((i / 7) % 5) as u8).collect();
+        let noisy: Vec<u8> = (0..20_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 27) as u8 % 16)
+            .collect();
+        for (indices, bits) in [
+            (vec![0], 2),
+            (vec![1, 1, 1, 1, 1, 1, 1], 2),
+            (patterned, 3),
+            (noisy, 4),
+        ] {
+            assert_eq!(unlzw(&lzw(&indices, bits), bits), indices);
+        }
+    }
+
+    #[test]
+    fn encode_png() {
+        let pixels = [Rgb(255, 0, 0), Rgb(0, 255, 0), Rgb(0, 0, 255), Rgb(1, 2, 3)];
+        let png = png(2, 2, &pixels).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 2]);
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut raw = vec![];
+        ZlibDecoder::new(&png[41..41 + idat_len])
+            .read_to_end(&mut raw)
+            .unwrap();
+        assert_eq!(raw, [0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 255, 1, 2, 3]);
+    }
+
+    #[test]
+    fn export_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-images-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        for format in [ImageFormat::Png, ImageFormat::Gif] {
+            let mut images = Images::new(&dir, 1, format, 3, 2, 10).unwrap();
+            images.palette(&[('#', Rgb(0, 200, 0))]);
+            for x in 0..3 {
+                images.begin_frame();
+                images.draw_char(x, 1, '#', Color::Default);
+                images.draw_str(0, 0, "ab", Color::Red);
+                images.end_frame();
+            }
+            images.sleep(Duration::from_secs(1));
+        }
+
+        for i in 0..3 {
+            assert!(dir.join(format!("frame-{i:05}.png")).exists());
+        }
+        let gif = fs::read(dir.join("animation.gif")).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], &[12, 0, 8, 0]);
+        assert_eq!(gif.last(), Some(&0x3b));
+        // three frames, and the last one stays up for the extra second
+        let delays: Vec<&[u8]> = gif
+            .windows(4)
+            .filter(|w| w[..3] == [0x21, 0xf9, 4])
+            .collect();
+        assert_eq!(delays.len(), 3);
+        let last = gif.windows(8).rfind(|w| w[..3] == [0x21, 0xf9, 4]).unwrap();
+        assert_eq!(u16::from_le_bytes([last[4], last[5]]), 110);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,314 @@
+//! Playback controls for the terminal visualizer: pause, stepping forward and back, speed, and
+//! jumping to a frame.
+//!
+//! [`Playback`] keeps the last few frames in a ring, so stepping back shows frames that were
+//! already drawn instead of asking the day to undo anything.  It only deals in keys and ticks (one
+//! tick per frame of the terminal's fps), so it doesn't need a terminal itself.
+
+use super::Frame;
+use std::collections::VecDeque;
+
+/// How many cells of history to keep, across every buffered frame.  Big frames get fewer of them.
+pub const HISTORY_CELLS: usize = 4_000_000;
+
+/// The fastest and slowest speeds, as powers of two.
+const MAX_SPEED: i32 = 4;
+
+/// How many columns the status bar and [`HELP`] need.
+pub const STATUS_WIDTH: u32 = 60;
+
+pub const HELP: &str = "space pause  ←/→ step  +/- speed  <n>g jump  q quit";
+
+/// What the viewer can ask for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Pause,
+    Forward,
+    Back,
+    Faster,
+    Slower,
+    /// One digit of a frame number to jump to.
+    Digit(u8),
+    /// Jump to the frame whose number was typed.
+    Jump,
+    Quit,
+}
+
+pub struct Playback {
+    history: VecDeque<Frame>,
+    capacity: usize,
+    /// How many frames the day has drawn; the newest is `drawn - 1`.
+    drawn: usize,
+    /// The frame on screen.
+    view: usize,
+    /// How many ticks the frame on screen has been up for.
+    shown: u32,
+    paused: bool,
+    /// The day was asked for one more frame while paused.
+    step: bool,
+    /// Speed as a power of two: at 2, every fourth frame is shown, and at -2 every frame is shown
+    /// for four ticks.
+    speed: i32,
+    /// A frame that hasn't been drawn yet, to skip ahead to.
+    target: Option<usize>,
+    /// The digits typed so far for a jump.
+    typed: String,
+    quit: bool,
+}
+
+impl Playback {
+    /// Keep up to `capacity` frames for stepping back through.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            history: VecDeque::new(),
+            capacity: capacity.max(1),
+            drawn: 0,
+            view: 0,
+            shown: 0,
+            paused: false,
+            step: false,
+            speed: 0,
+            target: None,
+            typed: String::new(),
+            quit: false,
+        }
+    }
+
+    /// The newest frame's number.
+    fn live(&self) -> usize {
+        self.drawn.saturating_sub(1)
+    }
+
+    /// The oldest frame's number that's still kept.
+    fn oldest(&self) -> usize {
+        self.drawn - self.history.len()
+    }
+
+    /// Add the frame the day just drew, and put it on screen.  Returns whether it should be shown
+    /// at all: frames are skipped while jumping ahead, and at high speeds.
+    pub fn push(&mut self, frame: Frame) -> bool {
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(frame);
+        self.drawn += 1;
+        self.view = self.live();
+        self.shown = 0;
+
+        if let Some(target) = self.target {
+            if self.view < target {
+                return false;
+            }
+            self.target = None;
+            self.paused = true;
+        }
+        self.paused || self.speed <= 0 || self.view.is_multiple_of(1 << self.speed)
+    }
+
+    pub fn key(&mut self, key: Key) {
+        if !matches!(key, Key::Digit(_) | Key::Jump) {
+            self.typed.clear();
+        }
+        match key {
+            Key::Pause => self.paused = !self.paused,
+            Key::Forward => {
+                self.paused = true;
+                if self.view < self.live() {
+                    self.view += 1;
+                    self.shown = 0;
+                } else {
+                    self.step = true;
+                }
+            }
+            Key::Back => {
+                self.paused = true;
+                if self.view > self.oldest() {
+                    self.view -= 1;
+                    self.shown = 0;
+                }
+            }
+            Key::Faster => self.speed = (self.speed + 1).min(MAX_SPEED),
+            Key::Slower => self.speed = (self.speed - 1).max(-MAX_SPEED),
+            Key::Digit(d) => self.typed.push(char::from(b'0' + d)),
+            Key::Jump => {
+                // frames are numbered from 1 on screen
+                if let Ok(n) = self.typed.parse::<usize>() {
+                    let n = n.saturating_sub(1);
+                    if n <= self.live() {
+                        self.view = n.max(self.oldest());
+                        self.shown = 0;
+                        self.paused = true;
+                    } else {
+                        self.target = Some(n);
+                        self.paused = false;
+                    }
+                }
+                self.typed.clear();
+            }
+            Key::Quit => self.quit = true,
+        }
+    }
+
+    /// One tick went by with the frame on screen.  Returns whether the day should draw its next
+    /// frame now.
+    pub fn tick(&mut self) -> bool {
+        if self.quit || self.target.is_some() {
+            return true;
+        }
+        if std::mem::take(&mut self.step) {
+            return true;
+        }
+        if self.paused {
+            return false;
+        }
+
+        self.shown += 1;
+        let ticks = 1 << (-self.speed).max(0);
+        if self.shown < ticks {
+            return false;
+        }
+        if self.view < self.live() {
+            // catching back up after stepping back
+            self.view += 1;
+            self.shown = 0;
+            return false;
+        }
+        true
+    }
+
+    /// The frame on screen.
+    pub fn frame(&self) -> Option<&Frame> {
+        self.history.get(self.view - self.oldest())
+    }
+
+    /// The line under the frame: the day's status, the frame number and the playback state.
+    pub fn status(&self) -> String {
+        let status = self.frame().map_or("", |frame| frame.status.as_str());
+        let state = if self.paused { "paused" } else { "playing" };
+        let speed = if self.speed >= 0 {
+            format!("x{}", 1 << self.speed)
+        } else {
+            format!("x1/{}", 1 << -self.speed)
+        };
+        let mut line = format!(
+            "{status}  frame {}/{}  {state} {speed}",
+            self.view + 1,
+            self.drawn
+        );
+        if !self.typed.is_empty() {
+            line.push_str(&format!("  jump to {}", self.typed));
+        }
+        line.trim_start().to_string()
+    }
+
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn quit(&self) -> bool {
+        self.quit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn frame(status: &str) -> Frame {
+        let mut frame = Frame::new(1, 1, Duration::ZERO);
+        frame.status = status.to_string();
+        frame
+    }
+
+    /// Tick until the day's asked for another frame, returning how many ticks that took.
+    fn ticks(playback: &mut Playback) -> usize {
+        (1..100).find(|_| playback.tick()).unwrap()
+    }
+
+    #[test]
+    fn pause_and_step() {
+        let mut playback = Playback::new(10);
+        assert!(playback.push(frame("round 1")));
+        assert_eq!(ticks(&mut playback), 1);
+        playback.push(frame("round 2"));
+
+        playback.key(Key::Pause);
+        assert!((0..10).all(|_| !playback.tick()));
+        assert_eq!(playback.status(), "round 2  frame 2/2  paused x1");
+
+        // back through the history, then forward again, and on to a new frame
+        playback.key(Key::Back);
+        playback.key(Key::Back);
+        assert_eq!(playback.frame(), Some(&frame("round 1")));
+        assert!(!playback.tick());
+        playback.key(Key::Forward);
+        assert!(!playback.tick());
+        assert_eq!(playback.frame(), Some(&frame("round 2")));
+        playback.key(Key::Forward);
+        assert!(playback.tick());
+        playback.push(frame("round 3"));
+        assert!(playback.paused() && !playback.tick());
+
+        // resuming after stepping back replays the history first
+        playback.key(Key::Back);
+        playback.key(Key::Back);
+        playback.key(Key::Pause);
+        assert_eq!(ticks(&mut playback), 3);
+        assert_eq!(playback.frame(), Some(&frame("round 3")));
+    }
+
+    #[test]
+    fn change_speed() {
+        let mut playback = Playback::new(10);
+        playback.key(Key::Slower);
+        playback.key(Key::Slower);
+        playback.push(frame(""));
+        assert_eq!(ticks(&mut playback), 4);
+        assert_eq!(playback.status(), "frame 1/1  playing x1/4");
+
+        for _ in 0..3 {
+            playback.key(Key::Faster);
+        }
+        // only every other frame is shown
+        let shown: Vec<bool> = (0..4).map(|_| playback.push(frame(""))).collect();
+        assert_eq!(shown, [false, true, false, true]);
+        assert_eq!(ticks(&mut playback), 1);
+
+        for _ in 0..10 {
+            playback.key(Key::Faster);
+        }
+        assert!(playback.status().ends_with("x16"));
+    }
+
+    #[test]
+    fn jump_to_frames() {
+        let mut playback = Playback::new(3);
+        for i in 1..=5 {
+            playback.push(frame(&format!("round {i}")));
+        }
+
+        // only the last three frames are kept
+        playback.key(Key::Digit(4));
+        assert!(playback.status().ends_with("jump to 4"));
+        playback.key(Key::Jump);
+        assert_eq!(playback.frame(), Some(&frame("round 4")));
+        assert!(playback.paused());
+        playback.key(Key::Digit(1));
+        playback.key(Key::Jump);
+        assert_eq!(playback.frame(), Some(&frame("round 3")));
+
+        // frames that haven't been drawn yet are skipped to, then paused on
+        playback.key(Key::Digit(8));
+        playback.key(Key::Jump);
+        assert!(playback.tick());
+        assert!(!playback.push(frame("round 6")));
+        assert!(playback.tick());
+        assert!(!playback.push(frame("round 7")));
+        assert!(playback.push(frame("round 8")));
+        assert!(playback.paused() && !playback.tick());
+
+        playback.key(Key::Quit);
+        assert!(playback.quit() && playback.tick());
+    }
+}
@@ -0,0 +1,140 @@
+//! A small SVG writer, for saving a day's final state as a picture (see [`super::snapshot`]).
+//!
+//! Coordinates are in the puzzle's own units, usually one per grid cell; the view box picks which
+//! part of that plane is shown, and every unit becomes [`SCALE`] pixels.
+
+use super::Rgb;
+use std::{fmt::Display, fs, io, path::Path};
+
+/// How many pixels one unit is drawn as.
+pub const SCALE: f64 = 10.0;
+
+/// An SVG document, built up one shape at a time.  Later shapes are drawn on top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    /// The left, top, width and height of the part of the plane that's shown.
+    view: (f64, f64, f64, f64),
+    body: String,
+}
+
+impl Svg {
+    /// An empty picture showing `width` by `height` units, with its top left corner at x,y.
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            view: (x, y, width, height),
+            body: String::new(),
+        }
+    }
+
+    /// An empty picture just big enough for every cell from `min` to `max`, inclusive, with a
+    /// cell's worth of margin all around.
+    pub fn around(min: (i32, i32), max: (i32, i32)) -> Self {
+        Self::new(
+            min.0 as f64 - 1.0,
+            min.1 as f64 - 1.0,
+            (max.0 - min.0) as f64 + 3.0,
+            (max.1 - min.1) as f64 + 3.0,
+        )
+    }
+
+    /// Fill the whole view.
+    pub fn background(&mut self, fill: Rgb) -> &mut Self {
+        let (x, y, width, height) = self.view;
+        self.rect(x, y, width, height, fill)
+    }
+
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, fill: Rgb) -> &mut Self {
+        self.body.push_str(&format!(
+            "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\" fill=\"{fill}\"/>\n"
+        ));
+        self
+    }
+
+    /// Fill the one-unit square with its top left corner at x,y.
+    pub fn cell(&mut self, x: i32, y: i32, fill: Rgb) -> &mut Self {
+        self.rect(x as f64, y as f64, 1.0, 1.0, fill)
+    }
+
+    pub fn circle(&mut self, x: f64, y: f64, r: f64, fill: Rgb) -> &mut Self {
+        self.body.push_str(&format!(
+            "<circle cx=\"{x}\" cy=\"{y}\" r=\"{r}\" fill=\"{fill}\"/>\n"
+        ));
+        self
+    }
+
+    /// A line through every point in turn.  Fewer than two points draw nothing.
+    pub fn polyline(&mut self, points: &[(f64, f64)], stroke: Rgb, width: f64) -> &mut Self {
+        if points.len() < 2 {
+            return self;
+        }
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+        self.body.push_str(&format!(
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{stroke}\" stroke-width=\"{width}\" \
+             stroke-linecap=\"round\" stroke-linejoin=\"round\"/>\n",
+            points.join(" ")
+        ));
+        self
+    }
+
+    /// Text with its baseline starting at x,y.
+    pub fn text(&mut self, x: f64, y: f64, size: f64, text: &str, fill: Rgb) -> &mut Self {
+        let text = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        self.body.push_str(&format!(
+            "<text x=\"{x}\" y=\"{y}\" font-size=\"{size}\" font-family=\"monospace\" \
+             fill=\"{fill}\">{text}</text>\n"
+        ));
+        self
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (x, y, width, height) = self.view;
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{x} {y} {width} {height}\" \
+             width=\"{}\" height=\"{}\">",
+            width * SCALE,
+            height * SCALE
+        )?;
+        write!(f, "{}", self.body)?;
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_svg() {
+        let mut svg = Svg::around((-1, 0), (1, 1));
+        svg.background(Rgb(0, 0, 0))
+            .cell(-1, 0, Rgb(255, 0, 16))
+            .circle(0.5, 0.5, 0.25, Rgb(1, 2, 3))
+            .polyline(&[(0.0, 0.0)], Rgb(0, 0, 0), 1.0)
+            .polyline(&[(0.0, 0.0), (1.5, 2.0)], Rgb(0, 0, 255), 0.2)
+            .text(0.0, 2.0, 1.0, "a<b & c", Rgb(255, 255, 255));
+
+        assert_eq!(
+            svg.to_string(),
+            "\
+<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-2 -1 5 4\" width=\"50\" height=\"40\">
+<rect x=\"-2\" y=\"-1\" width=\"5\" height=\"4\" fill=\"#000000\"/>
+<rect x=\"-1\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ff0010\"/>
+<circle cx=\"0.5\" cy=\"0.5\" r=\"0.25\" fill=\"#010203\"/>
+<polyline points=\"0,0 1.5,2\" fill=\"none\" stroke=\"#0000ff\" stroke-width=\"0.2\" \
+stroke-linecap=\"round\" stroke-linejoin=\"round\"/>
+<text x=\"0\" y=\"2\" font-size=\"1\" font-family=\"monospace\" fill=\"#ffffff\">a&lt;b &amp; c</text>
+</svg>
+"
+        );
+    }
+}
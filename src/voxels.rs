@@ -0,0 +1,351 @@
+//! 3D geometry for the voxel puzzles: points, their neighbourhoods, bounding boxes, and a set of
+//! filled voxels that can be flood filled, split into connected pieces, and measured.
+//!
+//! Flood fills use a queue rather than recursion, so big open spaces can't overflow the stack.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The six points sharing a face with this one: -x, +x, -y, +y, -z, +z.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        const FACES: [Point3; 6] = [
+            Point3::new(-1, 0, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, -1),
+            Point3::new(0, 0, 1),
+        ];
+        FACES.into_iter().map(move |d| self + d)
+    }
+
+    /// The 26 points sharing a face, an edge, or a corner with this one.
+    pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Point3::new(x, y, z))))
+            .filter(|&d| d != Point3::default())
+            .map(move |d| self + d)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// The smallest box around some points, faces included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Bounds3 {
+    /// The bounds of a single point.
+    pub fn new(p: Point3) -> Self {
+        Self { min: p, max: p }
+    }
+
+    /// The bounds of every point, unless there aren't any.
+    pub fn of(points: impl IntoIterator<Item = Point3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for p in points {
+            bounds.include(p);
+        }
+        Some(bounds)
+    }
+
+    /// Grow to take in another point.
+    pub fn include(&mut self, p: Point3) {
+        self.min = Point3::new(
+            self.min.x.min(p.x),
+            self.min.y.min(p.y),
+            self.min.z.min(p.z),
+        );
+        self.max = Point3::new(
+            self.max.x.max(p.x),
+            self.max.y.max(p.y),
+            self.max.z.max(p.z),
+        );
+    }
+
+    /// The same box with `by` more on every side.
+    pub fn grow(self, by: i32) -> Self {
+        let by = Point3::new(by, by, by);
+        Self {
+            min: self.min - by,
+            max: self.max + by,
+        }
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    /// The width, height and depth.
+    pub fn size(&self) -> Point3 {
+        self.max - self.min + Point3::new(1, 1, 1)
+    }
+
+    /// Every point in the box, z slowest and x fastest.
+    pub fn points(self) -> impl Iterator<Item = Point3> {
+        (self.min.z..=self.max.z).flat_map(move |z| {
+            (self.min.y..=self.max.y)
+                .flat_map(move |y| (self.min.x..=self.max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+/// A set of filled voxels.  Everything else is empty.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Voxels {
+    filled: HashSet<Point3>,
+}
+
+impl Voxels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.filled.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.filled.is_empty()
+    }
+
+    /// Fill a voxel, returning whether it was empty.
+    pub fn insert(&mut self, p: Point3) -> bool {
+        self.filled.insert(p)
+    }
+
+    /// Empty a voxel, returning whether it was filled.
+    pub fn remove(&mut self, p: Point3) -> bool {
+        self.filled.remove(&p)
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        self.filled.contains(&p)
+    }
+
+    /// Every filled voxel, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = Point3> + '_ {
+        self.filled.iter().copied()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds3> {
+        Bounds3::of(self.iter())
+    }
+
+    /// The empty voxels reachable from `start` through their faces without leaving `within`.
+    /// Nothing is reachable from a filled voxel, or from outside `within`.
+    pub fn flood_fill(&self, start: Point3, within: Bounds3) -> Voxels {
+        let mut reached = Voxels::new();
+        if self.contains(start) || !within.contains(start) {
+            return reached;
+        }
+        let mut queue = VecDeque::from([start]);
+        reached.insert(start);
+        while let Some(p) = queue.pop_front() {
+            for n in p.neighbours6() {
+                if within.contains(n) && !self.contains(n) && reached.insert(n) {
+                    queue.push_back(n);
+                }
+            }
+        }
+        reached
+    }
+
+    /// The pieces that are joined by faces, in no particular order.
+    pub fn components(&self) -> Vec<Voxels> {
+        let mut seen = HashSet::new();
+        let mut components = vec![];
+        for start in self.iter() {
+            if !seen.insert(start) {
+                continue;
+            }
+            let mut component = Voxels::new();
+            let mut queue = VecDeque::from([start]);
+            while let Some(p) = queue.pop_front() {
+                component.insert(p);
+                for n in p.neighbours6() {
+                    if self.contains(n) && seen.insert(n) {
+                        queue.push_back(n);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// How many faces of filled voxels touch an empty one, inside or out.
+    pub fn surface_area(&self) -> usize {
+        self.iter()
+            .flat_map(Point3::neighbours6)
+            .filter(|&n| !self.contains(n))
+            .count()
+    }
+
+    /// The empty voxels that can reach the outside.  Only the box one voxel bigger than the
+    /// filled ones is searched; past that everything's outside anyway.
+    fn outside(&self) -> Option<(Voxels, Bounds3)> {
+        let space = self.bounds()?.grow(1);
+        Some((self.flood_fill(space.min, space), space))
+    }
+
+    /// How many faces of filled voxels can be reached from outside, leaving out the ones facing
+    /// into enclosed pockets.
+    pub fn exterior_surface_area(&self) -> usize {
+        let Some((outside, _)) = self.outside() else {
+            return 0;
+        };
+        outside
+            .iter()
+            .flat_map(Point3::neighbours6)
+            .filter(|&n| self.contains(n))
+            .count()
+    }
+
+    /// The empty voxels that are enclosed, with no way out.
+    pub fn pockets(&self) -> Voxels {
+        let Some((outside, space)) = self.outside() else {
+            return Voxels::new();
+        };
+        space
+            .points()
+            .filter(|&p| !self.contains(p) && !outside.contains(p))
+            .collect()
+    }
+}
+
+impl FromIterator<Point3> for Voxels {
+    fn from_iter<I: IntoIterator<Item = Point3>>(iter: I) -> Self {
+        Self {
+            filled: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Point3> for Voxels {
+    fn extend<I: IntoIterator<Item = Point3>>(&mut self, iter: I) {
+        self.filled.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3x3x3 shell with no corners or edges, around one empty voxel at 2,2,2.
+    fn shell() -> Voxels {
+        Point3::new(2, 2, 2).neighbours6().collect()
+    }
+
+    #[test]
+    fn points_and_bounds() {
+        let p = Point3::new(1, -2, 3);
+        assert_eq!(p.manhattan(Point3::default()), 6);
+        assert_eq!(p.neighbours6().count(), 6);
+        assert!(p.neighbours6().all(|n| n.manhattan(p) == 1));
+        let around: HashSet<_> = p.neighbours26().collect();
+        assert_eq!(around.len(), 26);
+        assert!(!around.contains(&p));
+
+        let bounds = Bounds3::of([p, Point3::new(-1, 0, 0)]).unwrap();
+        assert_eq!(bounds.size(), Point3::new(3, 3, 4));
+        assert_eq!(bounds.points().count(), 36);
+        assert!(bounds.contains(Point3::new(0, -1, 2)));
+        assert!(!bounds.contains(Point3::new(0, 1, 2)));
+        assert_eq!(bounds.grow(1).min, Point3::new(-2, -3, -1));
+        assert_eq!(Bounds3::of([]), None);
+    }
+
+    #[test]
+    fn surface_areas() {
+        let mut pair = Voxels::new();
+        pair.insert(Point3::new(1, 1, 1));
+        assert_eq!(pair.surface_area(), 6);
+        pair.insert(Point3::new(2, 1, 1));
+        assert_eq!(pair.surface_area(), 10);
+        assert_eq!(pair.exterior_surface_area(), 10);
+
+        let shell = shell();
+        assert_eq!(shell.surface_area(), 36);
+        assert_eq!(shell.exterior_surface_area(), 30);
+        assert_eq!(shell.pockets(), Voxels::from_iter([Point3::new(2, 2, 2)]));
+        assert_eq!(Voxels::new().exterior_surface_area(), 0);
+    }
+
+    #[test]
+    fn fills_and_components() {
+        let shell = shell();
+        let inside = Point3::new(2, 2, 2);
+        let space = shell.bounds().unwrap();
+        assert_eq!(shell.flood_fill(inside, space).len(), 1);
+        assert_eq!(shell.flood_fill(inside, space.grow(1)).len(), 1);
+        assert!(shell.flood_fill(Point3::new(2, 2, 1), space).is_empty());
+        // the box's corners and edges are all joined up, but not to the middle
+        assert_eq!(shell.flood_fill(space.min, space).len(), 27 - 7);
+        assert_eq!(
+            shell.flood_fill(space.min, space.grow(1)).len(),
+            5 * 5 * 5 - 7
+        );
+
+        // the shell's faces only touch at their edges
+        assert_eq!(shell.components().len(), 6);
+        let mut joined = shell.clone();
+        joined.insert(inside);
+        assert_eq!(joined.components(), [joined.clone()]);
+
+        // a long, thin space doesn't need a deep stack
+        let corridor = Bounds3 {
+            min: Point3::new(0, 0, 0),
+            max: Point3::new(0, 0, 99_999),
+        };
+        assert_eq!(
+            Voxels::new().flood_fill(corridor.min, corridor).len(),
+            100_000
+        );
+    }
+}
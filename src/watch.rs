@@ -0,0 +1,187 @@
+//! `--watch`: re-run a day whenever its files change.
+//!
+//! The files are polled rather than watched with OS notifications, and the list of files is
+//! rebuilt on every poll, so example files created after starting are picked up too.  Each run is
+//! a fresh `cargo run` (or `cargo test`), so changes to the source are compiled in.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the files are checked for changes.
+const POLL: Duration = Duration::from_millis(500);
+
+/// What to run when something changes.
+pub enum Target {
+    /// The runner, with the arguments it was given (minus the watch flags).
+    Run(Vec<String>),
+    /// The day's tests.
+    Tests,
+}
+
+/// The last modified time of every watched file.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Watch a day's files (every day's, for day 255) and run the target whenever they change.  This
+/// never returns.
+pub fn watch(year: u16, day: u8, target: Target) -> ! {
+    let mut last = None;
+    loop {
+        let current = snapshot(&watched(Path::new("."), year, day));
+        if last.as_ref() != Some(&current) {
+            // clear the screen and move the cursor to the top left
+            print!("\x1b[2J\x1b[H");
+            io::stdout().flush().ok();
+            if let Err(e) = run(year, day, &target) {
+                eprintln!("Error: couldn't run cargo: {e}");
+            }
+            println!("\nwatching for changes...");
+            last = Some(current);
+        }
+        thread::sleep(POLL);
+    }
+}
+
+fn run(year: u16, day: u8, target: &Target) -> io::Result<()> {
+    let mut cargo = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    match target {
+        Target::Run(_) => cargo.args(["run", "-q"]),
+        Target::Tests => cargo.args(["test", "-q"]),
+    };
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    match target {
+        Target::Run(args) => cargo.arg("--").args(args),
+        Target::Tests if day == 255 => cargo.arg(format!("y{year}::")),
+        Target::Tests => cargo.arg(format!("y{year}::d{day}::")),
+    };
+
+    cargo.status()?;
+    Ok(())
+}
+
+/// The files that belong to a day: its source (and any submodules), its input and answers, and
+/// every one of its examples and their answers.  Day 255 means every day.
+fn watched(root: &Path, year: u16, day: u8) -> Vec<PathBuf> {
+    let is_day = |name: &str| {
+        let Some(rest) = name.strip_prefix('d') else {
+            return false;
+        };
+        let (num, rest) = rest.split_at(
+            rest.find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len()),
+        );
+        !num.is_empty()
+            && (day == 255 || num == day.to_string())
+            && (rest.is_empty() || rest.starts_with('-') || rest.starts_with('.'))
+    };
+
+    let mut files = vec![];
+    for dir in [
+        root.join(format!("src/y{year}")),
+        root.join(format!("input/{year}")),
+        root.join(format!("examples/{year}")),
+        root.join(format!("answers/{year}")),
+    ] {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if !is_day(name) {
+                continue;
+            }
+            if path.is_dir() {
+                // a day's submodules, like src/y2022/d7/parse.rs
+                files.extend(
+                    fs::read_dir(&path)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                        .filter(|path| path.extension().is_some_and(|ext| ext == "rs")),
+                );
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .filter_map(|path| Some((path.clone(), fs::metadata(path).ok()?.modified().ok()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watched_files() {
+        let root = std::env::temp_dir().join(format!("aoc2022-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in [
+            "src/y2022/d7",
+            "input/2022",
+            "examples/2022",
+            "answers/2022",
+        ] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in [
+            "src/y2022/d7.rs",
+            "src/y2022/d7/parse.rs",
+            "src/y2022/d70.rs",
+            "src/y2022/d1.rs",
+            "input/2022/d7",
+            "examples/2022/d7",
+            "examples/2022/d7.answers",
+            "examples/2022/d77",
+            "answers/2022/d7",
+        ] {
+            fs::write(root.join(file), "").unwrap();
+        }
+
+        let names = |day| {
+            watched(&root, 2022, day)
+                .iter()
+                .map(|path| path.strip_prefix(&root).unwrap().display().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(7),
+            [
+                "answers/2022/d7",
+                "examples/2022/d7",
+                "examples/2022/d7.answers",
+                "input/2022/d7",
+                "src/y2022/d7/parse.rs",
+                "src/y2022/d7.rs",
+            ]
+        );
+        assert_eq!(names(1), ["src/y2022/d1.rs"]);
+        assert_eq!(names(255).len(), 9);
+
+        // a new example is noticed
+        let before = snapshot(&watched(&root, 2022, 7));
+        fs::write(root.join("examples/2022/d7-2"), "").unwrap();
+        let after = snapshot(&watched(&root, 2022, 7));
+        assert_ne!(before, after);
+        assert_eq!(after.len(), 7);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
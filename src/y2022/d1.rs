@@ -0,0 +1,58 @@
+use crate::{AocError, Result, Solution};
+
+type Packs = [u32; 3];
+type Parsed = Packs;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    let mut packs: [u32; 3] = [0, 0, 0];
+    let mut pack: u32 = 0;
+    for (i, line) in input.lines().enumerate() {
+        match line.parse::<u32>() {
+            Ok(line_cals) => pack += line_cals,
+            Err(_) if !line.trim().is_empty() => {
+                return Err(AocError::parse_line(i, "expected a number of calories"));
+            }
+            Err(_) => {
+                // blank line, start a new pack
+                for seen_pack in packs.iter_mut() {
+                    if pack > *seen_pack {
+                        *seen_pack = pack;
+                        break;
+                    }
+                }
+                pack = 0;
+                packs.sort();
+            }
+        }
+    }
+
+    Ok(packs)
+}
+
+pub fn part1(packs: Parsed) -> u32 {
+    *packs.last().unwrap()
+}
+
+pub fn part2(packs: Parsed) -> u32 {
+    packs.iter().sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Model<'input> = Parsed;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
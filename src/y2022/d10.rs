@@ -1,15 +1,22 @@
+use crate::{AocError, Result, Solution};
 use std::fmt::Write as _;
-use std::str::Lines;
+use std::slice::Iter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instr {
+    Noop,
+    Addx(i32),
+}
 
 struct Device<'input> {
-    input: Lines<'input>,
+    input: Iter<'input, Instr>,
     pending: i32,
     x: i32,
     cycle: i32,
 }
 
 impl<'input> Device<'input> {
-    fn new(input: Lines<'input>) -> Self {
+    fn new(input: Iter<'input, Instr>) -> Self {
         Self {
             input,
             pending: 0,
@@ -28,10 +35,8 @@ impl Iterator for Device<'_> {
 
         // use this cycle to either: apply a pending addx, OR fetch the next command
         if self.pending == 0 {
-            let mut tokens = self.input.next().unwrap_or("noop").split_whitespace();
-            let cmd = tokens.next().unwrap_or("noop");
-            if let "addx" = cmd {
-                self.pending = tokens.next().unwrap().parse::<i32>().unwrap_or(0);
+            if let Some(Instr::Addx(v)) = self.input.next() {
+                self.pending = *v;
             }
         } else {
             self.x += self.pending;
@@ -41,14 +46,27 @@ impl Iterator for Device<'_> {
     }
 }
 
-type Parsed = String;
+type Parsed = Vec<Instr>;
 
-pub fn parse(input: String) -> Parsed {
+pub fn parse(input: String) -> Result<Parsed> {
     input
+        .lines()
+        .enumerate()
+        .map(
+            |(i, line)| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                ["noop"] => Ok(Instr::Noop),
+                ["addx", v] => v
+                    .parse()
+                    .map(Instr::Addx)
+                    .map_err(|_| AocError::parse_line(i, "expected a number to add")),
+                _ => Err(AocError::parse_line(i, "expected `noop` or `addx`")),
+            },
+        )
+        .collect()
 }
 
-pub fn part1(input: Parsed) -> i32 {
-    let mut dev = Device::new(input.lines());
+pub fn part1(program: Parsed) -> i32 {
+    let mut dev = Device::new(program.iter());
 
     [20, 40, 40, 40, 40, 40]
         .iter()
@@ -56,8 +74,8 @@ pub fn part1(input: Parsed) -> i32 {
         .sum::<i32>()
 }
 
-pub fn part2(input: Parsed) -> String {
-    let mut dev = Device::new(input.lines());
+pub fn part2(program: Parsed) -> String {
+    let mut dev = Device::new(program.iter());
     let mut answer = String::from("\n");
 
     // draw 6 rows
@@ -76,12 +94,33 @@ pub fn part2(input: Parsed) -> String {
     answer
 }
 
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Model<'input> = Parsed;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model).to_string())
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
 // #[test]
 // fn day10_part1_small_test() {
 //     let ex = "noop
 // addx 3
 // addx -5";
-//     let mut dev = Device::new(ex.lines());
+//     let program = parse(ex.to_string()).unwrap();
+//     let mut dev = Device::new(program.iter());
 //
 //     assert_eq!(dev.next().unwrap(), (1, 1 * 1));
 //     assert_eq!(dev.next().unwrap(), (1, 2 * 1));
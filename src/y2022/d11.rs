@@ -1,11 +1,23 @@
-type Parsed = String;
+use crate::{AocError, Result, Solution};
 
-pub fn parse(input: String) -> Parsed {
-    input
+type Parsed = Vec<Monkey>;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    let blocks: Vec<&str> = input.trim_end().split("\n\n").collect();
+    if blocks.len() < 2 {
+        return Err(AocError::parse_line(0, "expected at least two monkeys"));
+    }
+
+    let mut monkeys = vec![];
+    let mut first_line = 0;
+    for block in &blocks {
+        monkeys.push(Monkey::parse(block, first_line, blocks.len())?);
+        first_line += block.lines().count() + 1;
+    }
+    Ok(monkeys)
 }
 
-pub fn part1(input: Parsed) -> u64 {
-    let mut monkeys: Vec<Monkey> = input.split("\n\n").map(Monkey::from).collect();
+pub fn part1(mut monkeys: Parsed) -> u64 {
     let mut airborne: Vec<Vec<Item>> = vec![vec![]; monkeys.len()];
 
     let three = 3;
@@ -23,7 +35,7 @@ pub fn part1(input: Parsed) -> u64 {
                     },
                     MathOp::Add => match &monkey.op.value {
                         Some(val) => item.worry + val,
-                        None => unreachable!(),
+                        None => item.worry + item.worry,
                     },
                 };
 
@@ -46,8 +58,7 @@ pub fn part1(input: Parsed) -> u64 {
         .product()
 }
 
-pub fn part2(input: Parsed) -> u64 {
-    let mut monkeys: Vec<Monkey> = input.split("\n\n").map(Monkey::from).collect();
+pub fn part2(mut monkeys: Parsed) -> u64 {
     let mut airborne: Vec<Vec<Item>> = vec![vec![]; monkeys.len()];
 
     let max: u64 = monkeys.iter().map(|m| &m.test.div).product();
@@ -65,9 +76,7 @@ pub fn part2(input: Parsed) -> u64 {
                     },
                     MathOp::Add => match &monkey.op.value {
                         Some(val) => item.worry + val,
-                        None => {
-                            unreachable!();
-                        }
+                        None => item.worry + item.worry,
                     },
                 };
 
@@ -92,7 +101,27 @@ pub fn part2(input: Parsed) -> u64 {
         .product()
 }
 
-struct Monkey {
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Model<'input> = Parsed;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
+pub struct Monkey {
     items: Vec<Item>,
     op: Operation,
     test: Test,
@@ -119,62 +148,58 @@ struct Test {
     if_false: usize,
 }
 
-impl From<&str> for Monkey {
-    fn from(value: &str) -> Self {
-        let mut lines = value.lines();
-        lines.next().unwrap(); // discard monkey label
-
-        let items: Vec<Item> = lines
-            .next()
-            .unwrap()
-            .split(':')
-            .last()
-            .unwrap()
-            .split(',')
-            .map(|n| Item {
-                worry: n.trim().parse::<u64>().unwrap(),
-            })
-            .collect();
+impl Monkey {
+    /// Parse one monkey's notes, which start at `first_line` (0-based) of the input.  Monkeys can
+    /// only throw to the first `count`.
+    fn parse(notes: &str, first_line: usize, count: usize) -> Result<Self> {
+        let lines: Vec<&str> = notes.lines().map(str::trim).collect();
+        let bad =
+            |n: usize, what: &str| AocError::parse_line(first_line + n, format!("expected {what}"));
+        let line = |n: usize, prefix: &str| {
+            lines
+                .get(n)
+                .and_then(|line| line.strip_prefix(prefix))
+                .ok_or_else(|| bad(n, &format!("`{}`", prefix.trim_end())))
+        };
+        let target = |n: usize, prefix: &str| {
+            line(n, prefix)?
+                .parse::<usize>()
+                .ok()
+                .filter(|&target| target < count)
+                .ok_or_else(|| bad(n, &format!("a monkey from 0 to {}", count - 1)))
+        };
 
-        let mut op = lines.next().unwrap().split_whitespace().rev();
+        line(0, "Monkey ")?;
 
-        let op_val = match op.next().unwrap() {
-            "old" => None,
-            n => n.parse::<u64>().ok(),
+        let items: Vec<Item> = line(1, "Starting items:")?
+            .split(',')
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse::<u64>().map(|worry| Item { worry }))
+            .collect::<Result<_, _>>()
+            .map_err(|_| bad(1, "a list of worry levels"))?;
+
+        let (op_op, op_val) = match line(2, "Operation: new = old ")?.split_once(' ') {
+            Some(("*", val)) => (MathOp::Mul, val),
+            Some(("+", val)) => (MathOp::Add, val),
+            _ => return Err(bad(2, "`*` or `+`")),
         };
-        let op_op = match op.next().unwrap() {
-            "*" => MathOp::Mul,
-            _ => MathOp::Add,
+        let op_val = match op_val {
+            "old" => None,
+            n => Some(n.parse::<u64>().map_err(|_| bad(2, "a number or `old`"))?),
         };
 
-        let div = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
+        let div = line(3, "Test: divisible by ")?
             .parse::<u64>()
-            .unwrap();
-
-        let if_true = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-        let if_false = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        Monkey {
-            items: items.into_iter().collect(),
+            .ok()
+            .filter(|&div| div != 0)
+            .ok_or_else(|| bad(3, "a number to divide by"))?;
+
+        let if_true = target(4, "If true: throw to monkey ")?;
+        let if_false = target(5, "If false: throw to monkey ")?;
+
+        Ok(Monkey {
+            items,
             op: Operation {
                 op: op_op,
                 value: op_val,
@@ -185,7 +210,7 @@ impl From<&str> for Monkey {
                 if_false,
             },
             items_seen: 0,
-        }
+        })
     }
 }
 
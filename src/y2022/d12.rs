@@ -0,0 +1,222 @@
+use crate::{
+    grid::Grid,
+    vis::{self, svg::Svg, Rgb},
+    AocError, Result, Solution,
+};
+use petgraph::algo::astar;
+use petgraph::graph::NodeIndex;
+use petgraph::Graph;
+
+/// Unique height value for start that makes all adjacent heights reachable.
+const START_HEIGHT: u16 = 1;
+/// Unique height value for end node that is reachable from any adjacent height.
+const END_HEIGHT: u16 = 26;
+
+type Parsed = Grid<char>;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    let map: Grid<char> = Grid::parse(&input);
+
+    // short lines are padded out with spaces, so they show up here too
+    if let Some(p) = map.position(|&c| !matches!(c, 'a'..='z' | 'S' | 'E')) {
+        return Err(AocError::Parse {
+            line: p.y + 1,
+            column: p.x + 1,
+            msg: "expected a rectangle of heights from `a` to `z`, `S` or `E`".into(),
+        });
+    }
+    for mark in ['S', 'E'] {
+        if map.iter().filter(|&(_, &c)| c == mark).count() != 1 {
+            return Err(AocError::parse_line(
+                0,
+                format!("expected exactly one `{mark}`"),
+            ));
+        }
+    }
+
+    Ok(map)
+}
+
+pub fn part1(map: Parsed) -> Result<i32> {
+    let (graph, start, end) = climb(&map);
+
+    let path = astar(&graph, start, |finish| finish == end, |_| 1, |_| 1)
+        .ok_or_else(|| AocError::NoSolution("there's no way up from `S` to `E`".into()))?;
+
+    vis::snapshot(|| heightmap_svg(&graph, &path.1));
+
+    Ok(path.0)
+}
+
+pub fn part2(map: Parsed) -> Result<i32> {
+    let (graph, _, end) = climb(&map);
+
+    let mut dists = Vec::new();
+
+    for node_idx in graph.node_indices() {
+        let node = graph[node_idx];
+        if node.2 == START_HEIGHT {
+            let path = astar(&graph, node_idx, |finish| finish == end, |_| 1, |_| 1);
+
+            if let Some(path) = path {
+                dists.push(path);
+            }
+        }
+    }
+
+    let shortest = dists
+        .iter()
+        .min_by_key(|path| path.0)
+        .ok_or_else(|| AocError::NoSolution("there's no way up from any `a` to `E`".into()))?;
+
+    vis::snapshot(|| heightmap_svg(&graph, &shortest.1));
+
+    Ok(shortest.0)
+}
+
+fn height(c: char) -> u16 {
+    match c {
+        'S' => START_HEIGHT,
+        'E' => END_HEIGHT,
+        _ => (c as u16) - 96,
+    }
+}
+
+/// A graph of the heightmap with an edge wherever you can climb from one spot to the next, and
+/// the start and end nodes.  The map must have an `S` and an `E`, as [`parse`] makes sure.
+fn climb(map: &Grid<char>) -> (Graph<(usize, usize, u16), i32>, NodeIndex, NodeIndex) {
+    let mut graph = Graph::new();
+    let grid = Grid::from_fn(map.width(), map.height(), |p| {
+        graph.add_node((p.y, p.x, height(map[p])))
+    });
+
+    for p in grid.points() {
+        let node = graph[grid[p]];
+        for adj in grid.neighbours4(p) {
+            let adj_node = graph[grid[adj]];
+            if Some(adj_node.2) <= node.2.checked_add(1) {
+                graph.add_edge(grid[p], grid[adj], 1);
+            }
+        }
+    }
+
+    let start = grid[map.position(|&c| c == 'S').unwrap()];
+    let end = grid[map.position(|&c| c == 'E').unwrap()];
+
+    (graph, start, end)
+}
+
+/// The heightmap, lighter where it's higher, with a path across it.
+fn heightmap_svg<E>(graph: &Graph<(usize, usize, u16), E>, path: &[NodeIndex]) -> Svg {
+    let max = graph
+        .node_weights()
+        .fold((0, 0), |max, &(y, x, _)| (max.0.max(x), max.1.max(y)));
+
+    let mut svg = Svg::new(0.0, 0.0, max.0 as f64 + 1.0, max.1 as f64 + 1.0);
+    for &(y, x, height) in graph.node_weights() {
+        let shade = |lo: u16, hi: u16| (lo + (hi - lo) * (height - START_HEIGHT) / 25) as u8;
+        svg.cell(
+            x as i32,
+            y as i32,
+            Rgb(shade(10, 220), shade(60, 235), shade(30, 210)),
+        );
+    }
+
+    let points: Vec<(f64, f64)> = path
+        .iter()
+        .map(|&node| {
+            let (y, x, _) = graph[node];
+            (x as f64 + 0.5, y as f64 + 0.5)
+        })
+        .collect();
+    svg.polyline(&points, Rgb(205, 49, 49), 0.3);
+    if let (Some(&(sx, sy)), Some(&(ex, ey))) = (points.first(), points.last()) {
+        svg.circle(sx, sy, 0.4, Rgb(36, 114, 200));
+        svg.circle(ex, ey, 0.4, Rgb(229, 229, 16));
+    }
+    svg
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Model<'input> = Parsed;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part1(model)
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn day12_bad_input() {
+        assert_eq!(
+            parse("Sab\nab2\nccE".to_string()).unwrap_err().to_string(),
+            "parse error at line 2, column 3: expected a rectangle of heights from `a` to `z`, `S` or `E`"
+        );
+        assert!(parse("Sab\nabE\nccE".to_string()).is_err());
+        assert!(matches!(
+            part1(parse("Saz\nzzE".to_string()).unwrap()),
+            Err(AocError::NoSolution(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod svg_tests {
+    use super::*;
+
+    #[test]
+    fn day12_heightmap_svg() {
+        let mut graph: Graph<(usize, usize, u16), i32> = Graph::new();
+        let path = [
+            graph.add_node((0, 0, START_HEIGHT)),
+            graph.add_node((0, 1, 2)),
+            graph.add_node((1, 1, END_HEIGHT)),
+        ];
+        graph.add_node((1, 0, 3));
+        let svg = heightmap_svg(&graph, &path).to_string();
+
+        assert!(svg.contains("viewBox=\"0 0 2 2\""));
+        assert_eq!(svg.matches("<rect ").count(), 4);
+        // the lowest and highest spots
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"#0a3c1e\"/>"));
+        assert!(svg.contains("<rect x=\"1\" y=\"1\" width=\"1\" height=\"1\" fill=\"#dcebd2\"/>"));
+        assert!(svg.contains("points=\"0.5,0.5 1.5,0.5 1.5,1.5\""));
+        assert_eq!(svg.matches("<circle ").count(), 2);
+    }
+}
+
+// #[cfg(test)]
+// mod tests {
+//     use super::*;
+//
+//     const EX: &str = "Sabqponm\n\
+//                       abcryxxl\n\
+//                       accszExk\n\
+//                       acctuvwj\n\
+//                       abdefghi";
+//
+//     #[test]
+//     fn part1_test() {
+//         assert_eq!(part1_solve(EX), 31);
+//     }
+//
+//     // #[test]
+//     // fn part2_test() {
+//     //     assert_eq!(part2_solve(EX), 2713310158u64);
+//     // }
+// }
@@ -1,24 +1,34 @@
+use crate::{AocError, Result, Solution};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
-type Parsed = String;
+type Parsed = Vec<Data>;
 
-pub fn parse(input: String) -> Parsed {
-    input
+pub fn parse(input: String) -> Result<Parsed> {
+    let mut packets = vec![];
+    let mut last_line = 0;
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let data = serde_json::from_str::<Data>(line).map_err(|e| AocError::Parse {
+            line: i + 1,
+            column: e.column().max(1),
+            msg: "expected a packet, a list of numbers and lists".into(),
+        })?;
+        packets.push(data);
+        last_line = i;
+    }
+    if packets.len() % 2 != 0 {
+        return Err(AocError::parse_line(last_line, "expected packets in pairs"));
+    }
+    Ok(packets)
 }
 
 pub fn part1(input: Parsed) -> usize {
     let packets: Vec<Packet> = input
-        .lines()
-        .filter_map(|line| {
-            if line.is_empty() {
-                None
-            } else {
-                serde_json::from_str::<Data>(line)
-                    .ok()
-                    .map(|data| Packet { data, tag: None })
-            }
-        })
+        .into_iter()
+        .map(|data| Packet { data, tag: None })
         .collect();
 
     let correct = packets.chunks(2).enumerate().filter_map(|(i, chunk)| {
@@ -34,16 +44,8 @@ pub fn part1(input: Parsed) -> usize {
 
 pub fn part2(input: Parsed) -> usize {
     let mut packets: Vec<Packet> = input
-        .lines()
-        .filter_map(|line| {
-            if line.is_empty() {
-                None
-            } else {
-                serde_json::from_str::<Data>(line)
-                    .ok()
-                    .map(|data| Packet { data, tag: None })
-            }
-        })
+        .into_iter()
+        .map(|data| Packet { data, tag: None })
         .collect();
 
     // add divider packets
@@ -64,6 +66,26 @@ pub fn part2(input: Parsed) -> usize {
         * (1 + packets.iter().position(|p| p.tag == Some(6)).unwrap())
 }
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Packet {
     data: Data,
@@ -72,7 +94,7 @@ struct Packet {
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 #[serde(untagged)]
-enum Data {
+pub enum Data {
     Int(u8),
     List(Vec<Data>),
 }
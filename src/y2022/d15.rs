@@ -0,0 +1,201 @@
+use crate::{geom::Point, intervals::IntervalSet, AocError, Result, Solution};
+
+type Parsed = Vec<Record>;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    records(&input)
+}
+
+pub fn part1(records: Parsed) -> usize {
+    part1_solve(&records, 2000000)
+}
+
+pub fn part2(records: Parsed) -> Result<i64> {
+    part2_solve(&records, 4000000)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Model<'input> = Parsed;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model) as i64)
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
+}
+
+#[derive(Debug)]
+pub struct Record {
+    sensor: Point<i64>,
+    beacon: Point<i64>,
+    dist: i64,
+}
+
+/// Parse lines like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`.
+fn records(input: &str) -> Result<Vec<Record>> {
+    let mut records: Vec<Record> = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let coords = || -> Option<[i64; 4]> {
+            let line = line.strip_prefix("Sensor at x=")?;
+            let (sensor_x, line) = line.split_once(", y=")?;
+            let (sensor_y, line) = line.split_once(": closest beacon is at x=")?;
+            let (beacon_x, beacon_y) = line.split_once(", y=")?;
+            Some([
+                sensor_x.parse().ok()?,
+                sensor_y.parse().ok()?,
+                beacon_x.parse().ok()?,
+                beacon_y.parse().ok()?,
+            ])
+        };
+        let Some([sensor_x, sensor_y, beacon_x, beacon_y]) = coords() else {
+            return Err(AocError::parse_line(
+                i,
+                "expected `Sensor at x=.., y=..: closest beacon is at x=.., y=..`",
+            ));
+        };
+
+        let sensor = Point::new(sensor_x, sensor_y);
+        let beacon = Point::new(beacon_x, beacon_y);
+        let dist = sensor.manhattan(beacon);
+
+        records.push(Record {
+            sensor,
+            beacon,
+            dist,
+        });
+    }
+
+    Ok(records)
+}
+
+/// The x values on a row that are within range of some sensor.
+fn coverage(records: &[Record], row: i64) -> IntervalSet<i64> {
+    records
+        .iter()
+        .filter_map(|r| {
+            // how far the scan area reaches either side of the sensor on this row
+            let reach = r.dist - (r.sensor.y - row).abs();
+            (reach >= 0).then(|| (r.sensor.x - reach)..=(r.sensor.x + reach))
+        })
+        .collect()
+}
+
+fn part1_solve(records: &[Record], row: i64) -> usize {
+    let mut covered = coverage(records, row);
+
+    // a beacon is definitely where a beacon is
+    for r in records.iter().filter(|r| r.beacon.y == row) {
+        covered.remove(r.beacon.x..=r.beacon.x);
+    }
+
+    covered.total_len() as usize
+}
+
+fn part2_solve(records: &[Record], size: i64) -> Result<i64> {
+    // check around the perimeter of each sensor's scan area; the answer is guaranteed to lie just
+    // outside the perim.
+
+    let dr = (1, 1); // down right
+    let dl = (-1, 1); // down left
+    let ul = (-1, -1); // up left
+    let ur = (1, -1); // up right
+
+    // initial direction, down-right
+    let mut dir: (i64, i64);
+
+    let search_range = 0..=size;
+
+    for record in records {
+        let mut point = record.sensor;
+
+        dir = dr;
+
+        // starting with the top corner of the area, move clockwise around the perimeter of the
+        // scan area.  when considering each point, make sure it's inside the 0..=area range.
+
+        point.y = point.y - record.dist - 1;
+
+        // loop over every cell in the perimeter
+        for _ in 0..(4 * (record.dist + 1)) {
+            point.x += dir.0;
+            point.y += dir.1;
+
+            if !(search_range.contains(&point.x) && search_range.contains(&point.y)) {
+                // don't search outside the search area
+                continue;
+            }
+
+            if records.iter().all(|r| point.manhattan(r.sensor) > r.dist) {
+                return Ok(point.x * 4000000 + point.y);
+            }
+
+            // change direction
+            if dir == dr && point.x == record.sensor.x + record.dist + 1 {
+                dir = dl;
+            } else if dir == dl && point.y == record.sensor.y + record.dist + 1 {
+                dir = ul;
+            } else if dir == ul && point.x == record.sensor.x - record.dist - 1 {
+                dir = ur;
+            }
+        }
+    }
+
+    Err(AocError::NoSolution(format!(
+        "the sensors cover every spot from 0 to {size}"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1_solve(&records(EX).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn part2_test() {
+        assert_eq!(part2_solve(&records(EX).unwrap(), 20), Ok(56000011));
+    }
+
+    #[test]
+    fn bad_input() {
+        assert_eq!(
+            parse("garbage".to_string()).unwrap_err().to_string(),
+            "parse error at line 1, column 1: expected `Sensor at x=.., y=..: closest beacon is at x=.., y=..`"
+        );
+        // one sensor that sees the whole area
+        let everywhere = records("Sensor at x=2, y=2: closest beacon is at x=10, y=2").unwrap();
+        assert!(matches!(
+            part2_solve(&everywhere, 4),
+            Err(AocError::NoSolution(_))
+        ));
+    }
+}
@@ -1,23 +1,27 @@
+use crate::{AocError, Result, Solution};
 use petgraph::algo::floyd_warshall;
 use petgraph::prelude::*;
 use std::collections::HashMap;
 
 type Parsed<'a> = Vec<Valve<'a>>;
 
-pub fn parse(input: &str) -> Vec<Valve> {
+pub fn parse(input: &str) -> Result<Parsed<'_>> {
     let mut valves = vec![];
 
-    for line in input.lines() {
+    for (i, line) in input.lines().enumerate() {
+        let err = |msg: &str| AocError::parse_line(i, msg);
         let mut words = line.split_whitespace();
-        let name = words.nth(1).unwrap();
+        let name = words.nth(1).ok_or_else(|| err("expected `Valve <name>`"))?;
         let rate = words
             .nth(2)
-            .unwrap()
+            .ok_or_else(|| err("expected `has flow rate=<rate>;`"))?
             .replace("rate=", "")
             .replace(';', "")
             .parse::<u16>()
-            .unwrap();
-        words.nth(3).unwrap(); // "tunnels lead to valve"
+            .map_err(|_| err("expected a number for the flow rate"))?;
+        words
+            .nth(3)
+            .ok_or_else(|| err("expected `tunnels lead to valves`"))?;
         let mut exits = vec![];
         for word in words {
             exits.push(word.replace(',', ""));
@@ -34,7 +38,7 @@ pub fn parse(input: &str) -> Vec<Valve> {
         valves.push(valve);
     }
 
-    valves
+    Ok(valves)
 }
 
 pub fn part1(valves: Parsed) -> u16 {
@@ -74,6 +78,26 @@ pub fn part2(valves: Parsed) -> u16 {
     }
 }
 
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Model<'input> = Parsed<'input>;
+    type Answer = u16;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input)
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
 const START: &str = "AA";
 
 #[derive(Debug)]
@@ -1,5 +1,10 @@
+use crate::{
+    vis::{self, Color, Rgb, Visualizer},
+    AocError, Result, Solution,
+};
 use std::{
     collections::{HashMap, VecDeque},
+    fmt::Display,
     iter::{Cycle, Enumerate},
     slice::Iter,
     str::Chars,
@@ -7,24 +12,68 @@ use std::{
 
 type Parsed = String;
 
-pub fn parse(input: String) -> Parsed {
-    input
+/// The jets, which must be `<` and `>` only.
+pub fn parse(input: String) -> Result<Parsed> {
+    let jets = input.trim_end();
+    if let Some(col) = jets.find(|c| c != '<' && c != '>') {
+        return Err(AocError::parse_at(
+            jets,
+            &jets[col..],
+            "expected only `<` and `>`",
+        ));
+    }
+    if jets.is_empty() {
+        return Err(AocError::parse_line(0, "expected some jets"));
+    }
+    Ok(jets.to_string())
 }
 
 pub fn part1(input: Parsed) -> usize {
     let mut chamber = Chamber::new(&input);
 
-    chamber.nth(2022 - 1)
+    // one row for the floor
+    let mut vis = vis::open(9, HASH_SIZE as u32 + 1, 30);
+    chamber.nth(2022 - 1, &mut *vis)
 }
 
 pub fn part2(input: Parsed) -> usize {
     let mut chamber = Chamber::new(&input);
 
-    chamber.nth(1000000000000 - 1)
+    chamber.nth(1000000000000 - 1, &mut vis::Off)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
 }
 
 const HASH_SIZE: usize = 48;
 
+/// Cell colors for image exports.
+const PALETTE: &[(char, Rgb)] = &[
+    ('#', Rgb(181, 137, 90)),
+    ('.', Rgb(24, 24, 32)),
+    ('|', Rgb(128, 128, 128)),
+    ('+', Rgb(128, 128, 128)),
+    ('-', Rgb(128, 128, 128)),
+    ('~', Rgb(70, 70, 70)),
+];
+
 enum Shape {
     Plus,
     Corner,
@@ -193,7 +242,17 @@ impl<'a> Chamber<'a> {
         }
     }
 
-    fn nth(&mut self, total_rocks: usize) -> usize {
+    /// Draw the settled rocks, with the floor at the bottom of the frame.
+    fn draw(&self, vis: &mut dyn Visualizer) {
+        vis.begin_frame();
+        let top = HASH_SIZE as i32 - self.rocks.len() as i32;
+        vis.draw_str(0, top, &self.to_string(), Color::Default);
+        vis.end_frame();
+    }
+
+    fn nth(&mut self, total_rocks: usize, vis: &mut dyn Visualizer) -> usize {
+        vis.palette(PALETTE);
+        let mut drawing = vis.enabled();
         #[derive(Hash, PartialEq, Eq, Clone)]
         struct MemoKey {
             shape_idx: usize,
@@ -314,6 +373,14 @@ impl<'a> Chamber<'a> {
             // rest below the current peak)
             self.peak = self.peak.max(y + shape.height());
 
+            if drawing {
+                // stop drawing, but keep counting, when asked to quit
+                drawing = !vis.quit();
+                if drawing {
+                    self.draw(vis);
+                }
+            }
+
             // thanks Reddit.  I still don't understand why the rows of settled rocks are totally
             // irrelevant here.  It _really_ seems like they need to be part of the hash as well,
             // instead of only the shape and jet matching up.  The only thing I can think of is
@@ -349,6 +416,79 @@ impl<'a> Chamber<'a> {
     }
 }
 
+/// The settled rocks, topmost row first.  Once the bottom rows have been pruned, the floor is
+/// drawn as `~`.
+impl Display for Chamber<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for rock in self.rocks.iter().rev() {
+            let row: String = (0..7)
+                .rev()
+                .map(|bit| if rock & (1 << bit) != 0 { '#' } else { '.' })
+                .collect();
+            writeln!(f, "|{row}|")?;
+        }
+        if self.prune_count == 0 {
+            writeln!(f, "+-------+")
+        } else {
+            writeln!(f, "|~~~~~~~|")
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn day17_bad_input() {
+        assert_eq!(
+            parse(">><x<".to_string()).unwrap_err().to_string(),
+            "parse error at line 1, column 4: expected only `<` and `>`"
+        );
+        assert!(parse("\n".to_string()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod vis_tests {
+    use super::*;
+    use crate::vis::Recorder;
+
+    const EX: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn day17_part1_visualized() {
+        let mut rec = Recorder::new(9, HASH_SIZE as u32 + 1, 30);
+        let mut chamber = Chamber::new(EX);
+        assert_eq!(chamber.nth(9, &mut rec), 17);
+        assert_eq!(rec.frames.len(), 10);
+
+        let last = rec.frames.last().unwrap().to_string();
+        let tower: Vec<&str> = last.lines().rev().take(18).collect();
+        let expected = [
+            "+-------+",
+            "|..####.|",
+            "|...#...|",
+            "|..###..|",
+            "|#####..|",
+            "|..#.#..|",
+            "|..#.#..|",
+            "|....#..|",
+            "|....##.|",
+            "|....##.|",
+            "|.####..|",
+            "|..#....|",
+            "|.###...|",
+            "|######.|",
+            "|##..##.|",
+            "|....##.|",
+            "|....#..|",
+            "|....#..|",
+        ];
+        assert_eq!(tower, expected);
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -0,0 +1,188 @@
+use crate::{
+    vis::{self, svg::Svg, Rgb},
+    voxels::{Bounds3, Point3, Voxels},
+    AocError, Result, Solution,
+};
+
+type Parsed = Voxels;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            cube(line).ok_or_else(|| AocError::parse_line(i, "expected a cube like `2,2,2`"))
+        })
+        .collect()
+}
+
+pub fn part1(droplet: Parsed) -> usize {
+    vis::snapshot(|| layers_svg(&droplet, &Voxels::new()));
+
+    droplet.surface_area()
+}
+
+pub fn part2(droplet: Parsed) -> usize {
+    // whatever the steam can't reach, and isn't lava, is trapped air
+    vis::snapshot(|| layers_svg(&droplet, &droplet.pockets()));
+
+    droplet.exterior_surface_area()
+}
+
+/// A cube from its `x,y,z` line.
+fn cube(line: &str) -> Option<Point3> {
+    match line.split(',').collect::<Vec<_>>()[..] {
+        [x, y, z] => Some(Point3::new(
+            x.parse().ok()?,
+            y.parse().ok()?,
+            z.parse().ok()?,
+        )),
+        _ => None,
+    }
+}
+
+/// The droplet one z layer at a time, laid out in rows of tiles, with any trapped air pockets.
+fn layers_svg(cubes: &Voxels, pockets: &Voxels) -> Svg {
+    let Some(bounds) = Bounds3::of(cubes.iter().chain(pockets.iter())) else {
+        return Svg::new(0.0, 0.0, 1.0, 1.0);
+    };
+    let (min, size) = (bounds.min, bounds.size());
+
+    let layers = size.z as usize;
+    let columns = (layers as f64).sqrt().ceil() as usize;
+    // each tile has a label row above it, and a cell of space around it
+    let (tile_width, tile_height) = (size.x + 1, size.y + 3);
+    let tile = |z: i32| {
+        let i = (z - min.z) as usize;
+        (
+            (i % columns) as i32 * tile_width,
+            (i / columns) as i32 * tile_height,
+        )
+    };
+
+    let mut svg = Svg::new(
+        -1.0,
+        -1.0,
+        (columns as i32 * tile_width + 1) as f64,
+        (layers.div_ceil(columns) as i32 * tile_height + 1) as f64,
+    );
+    svg.background(Rgb(20, 20, 28));
+    for z in min.z..=bounds.max.z {
+        let (tx, ty) = tile(z);
+        let label = format!("z={z}");
+        svg.text(tx as f64, ty as f64 + 1.0, 1.2, &label, Rgb(204, 204, 204));
+        svg.rect(
+            tx as f64,
+            ty as f64 + 2.0,
+            size.x as f64,
+            size.y as f64,
+            Rgb(40, 40, 48),
+        );
+    }
+    for (cubes, fill) in [(cubes, Rgb(205, 80, 30)), (pockets, Rgb(36, 114, 200))] {
+        for cube in cubes.iter() {
+            let (tx, ty) = tile(cube.z);
+            svg.cell(tx + cube.x - min.x, ty + 2 + cube.y - min.y, fill);
+        }
+    }
+    svg
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn day18_bad_input() {
+        assert_eq!(
+            parse("1,1,1\n2,x,1".to_string()).unwrap_err().to_string(),
+            "parse error at line 2, column 1: expected a cube like `2,2,2`"
+        );
+        assert!(parse("1,1".to_string()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod svg_tests {
+    use super::*;
+
+    #[test]
+    fn day18_layers_svg() {
+        let cube = Point3::new;
+        // a hollow 3x3x3 shell, without its corners and edges, around one pocket of air
+        let cubes = Voxels::from_iter([
+            cube(2, 2, 1),
+            cube(2, 2, 3),
+            cube(1, 2, 2),
+            cube(3, 2, 2),
+            cube(2, 1, 2),
+            cube(2, 3, 2),
+        ]);
+        assert_eq!(cubes.pockets(), Voxels::from_iter([cube(2, 2, 2)]));
+        let svg = layers_svg(&cubes, &cubes.pockets()).to_string();
+
+        // three layers of 3x3 in two columns, so two rows of tiles
+        assert!(svg.contains("viewBox=\"-1 -1 9 13\""));
+        assert_eq!(svg.matches(">z=").count(), 3);
+        assert_eq!(svg.matches("fill=\"#cd501e\"").count(), 6);
+        // the pocket is in the middle of the second tile
+        assert!(svg.contains("<rect x=\"5\" y=\"3\" width=\"1\" height=\"1\" fill=\"#2472c8\"/>"));
+    }
+}
+
+// #[cfg(test)]
+// mod tests {
+//     use super::*;
+//
+//     const EX: &str = "2,2,2
+// 1,2,2
+// 3,2,2
+// 2,1,2
+// 2,3,2
+// 2,2,1
+// 2,2,3
+// 2,2,4
+// 2,2,6
+// 1,2,5
+// 3,2,5
+// 2,1,5
+// 2,3,5";
+//
+//     // const EX2: &str = "1,1,0
+//     // 0,1,1
+//     // 1,0,1
+//     // 1,2,1
+//     // 2,1,1
+//     // 1,1,2";
+//
+//     #[test]
+//     fn part1_test() {
+//         assert_eq!(part1_solve(EX), 64);
+//     }
+//
+//     #[test]
+//     fn part2_test() {
+//         assert_eq!(part2_solve(EX), 58);
+//         // assert_eq!(part2_solve(EX2), 30);
+//     }
+// }
@@ -1,22 +1,28 @@
+use crate::{AocError, Result, Solution};
 use std::{
     cmp::Ordering::{Equal, Greater, Less},
     collections::HashMap,
     ops::{Add, Mul, Sub},
 };
 
-type Parsed = String;
+type Parsed = Vec<Blueprint>;
 
-pub fn parse(input: String) -> Parsed {
+pub fn parse(input: String) -> Result<Parsed> {
     input
-}
-
-pub fn part1(input: Parsed) -> i32 {
-    let blueprint: Vec<Blueprint> = input
         .lines()
         .enumerate()
-        .map(|(i, line)| Blueprint::new(i + 1, line))
-        .collect();
+        .map(|(i, line)| {
+            Blueprint::parse(i + 1, line).ok_or_else(|| {
+                AocError::parse_line(
+                    i,
+                    "expected a blueprint like `Blueprint 1: Each ore robot costs 4 ore. ...`",
+                )
+            })
+        })
+        .collect()
+}
 
+pub fn part1(blueprint: Parsed) -> i32 {
     // figure out some max required resources per minute
 
     // println!("{:#?}", blueprint);
@@ -41,7 +47,7 @@ pub fn part1(input: Parsed) -> i32 {
         /*|| cant_exceed_max*/
         {
             if wallet.geo > *max {
-                println!(
+                eprintln!(
                     "  blueprint {} got {} geodes: quality score {}",
                     bp.id,
                     wallet.geo,
@@ -110,7 +116,7 @@ pub fn part1(input: Parsed) -> i32 {
         .iter()
         .map(|(bp, geos)| {
             let qual = bp.id * geos;
-            println!(
+            eprintln!(
                 "Blueprint {} got {} geos, quality score: {}",
                 bp.id, geos, qual
             );
@@ -119,13 +125,8 @@ pub fn part1(input: Parsed) -> i32 {
         .sum()
 }
 
-pub fn part2(input: Parsed) -> i32 {
-    let blueprint: Vec<Blueprint> = input
-        .lines()
-        .take(3)
-        .enumerate()
-        .map(|(i, line)| Blueprint::new(i + 1, line))
-        .collect();
+pub fn part2(mut blueprint: Parsed) -> i32 {
+    blueprint.truncate(3);
 
     // figure out some max required resources per minute
 
@@ -148,7 +149,7 @@ pub fn part2(input: Parsed) -> i32 {
         // base case
         if minutes == 0 || cant_exceed_max {
             if wallet.geo > *max {
-                println!(
+                eprintln!(
                     "  blueprint {} got {} geodes: quality score {}",
                     bp.id,
                     wallet.geo,
@@ -229,12 +230,32 @@ pub fn part2(input: Parsed) -> i32 {
     strats
         .iter()
         .map(|(bp, geos)| {
-            println!("Blueprint {} got {} geos", bp.id, geos);
+            eprintln!("Blueprint {} got {} geos", bp.id, geos);
             geos
         })
         .product()
 }
 
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Model<'input> = Parsed;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 struct Amount {
     ore: i32,
@@ -309,7 +330,7 @@ impl PartialOrd for Amount {
 }
 
 #[derive(Debug, Hash, PartialEq, Eq)]
-struct Blueprint {
+pub struct Blueprint {
     id: i32,
     ore_bot: Amount,
     clay_bot: Amount,
@@ -398,7 +419,7 @@ impl Bot {
 }
 
 impl Blueprint {
-    fn new(id: usize, line: &str) -> Self {
+    fn parse(id: usize, line: &str) -> Option<Self> {
         let mut words = line.split_whitespace();
 
         // Example line:
@@ -406,27 +427,27 @@ impl Blueprint {
 
         words.nth(5);
 
-        let ore_bot_ore = words.next().unwrap().parse::<i32>().unwrap();
+        let ore_bot_ore = words.next()?.parse::<i32>().ok()?;
 
         words.nth(4);
 
-        let clay_bot_ore = words.next().unwrap().parse::<i32>().unwrap();
+        let clay_bot_ore = words.next()?.parse::<i32>().ok()?;
 
         words.nth(4);
 
-        let obs_bot_ore = words.next().unwrap().parse::<i32>().unwrap();
+        let obs_bot_ore = words.next()?.parse::<i32>().ok()?;
 
         words.nth(1);
 
-        let obs_bot_clay = words.next().unwrap().parse::<i32>().unwrap();
+        let obs_bot_clay = words.next()?.parse::<i32>().ok()?;
 
         words.nth(4);
 
-        let geo_bot_ore = words.next().unwrap().parse::<i32>().unwrap();
+        let geo_bot_ore = words.next()?.parse::<i32>().ok()?;
 
         words.nth(1);
 
-        let geo_bot_obs = words.next().unwrap().parse::<i32>().unwrap();
+        let geo_bot_obs = words.next()?.parse::<i32>().ok()?;
 
         let ore_bot = Amount::new(ore_bot_ore, 0, 0, 0);
         let clay_bot = Amount::new(clay_bot_ore, 0, 0, 0);
@@ -456,14 +477,14 @@ impl Blueprint {
                 .max(geo_bot.geo),
         );
 
-        Self {
+        Some(Self {
             id: id as i32,
             ore_bot,
             clay_bot,
             obs_bot,
             geo_bot,
             maxpm,
-        }
+        })
     }
 }
 
@@ -1,50 +1,60 @@
-type Parsed = String;
+use crate::{AocError, Result, Solution};
 
-pub fn parse(input: String) -> Parsed {
-    input
-}
-
-fn part1_parse(input: String) -> Vec<(Move, Move)> {
-    let mut guide: Vec<(Move, Move)> = vec![];
-    for line in input.lines() {
-        let mut pair = line.split_whitespace().map(|m| m.into());
-        guide.push((pair.next().unwrap(), pair.next().unwrap()));
-    }
-    guide
-}
+/// Their move, then the second column read as a move (part 1) and as an outcome (part 2).
+type Parsed = Vec<(Move, Move, Outcome)>;
 
-fn part2_parse(input: String) -> Vec<(Move, Outcome)> {
-    let mut guide: Vec<(Move, Outcome)> = vec![];
-    for line in input.lines() {
-        let mut line_components = line.split_whitespace();
-        guide.push((
-            line_components.next().unwrap().into(),
-            line_components.next().unwrap().into(),
-        ));
+pub fn parse(input: String) -> Result<Parsed> {
+    let mut guide: Parsed = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let round = match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [theirs @ ("A" | "B" | "C"), yours @ ("X" | "Y" | "Z")] => {
+                (theirs.into(), yours.into(), yours.into())
+            }
+            _ => return Err(AocError::parse_line(i, "expected a round like `A Y`")),
+        };
+        guide.push(round);
     }
-    guide
+    Ok(guide)
 }
 
-pub fn part1(input: Parsed) -> u32 {
-    let guide = part1_parse(input);
+pub fn part1(guide: Parsed) -> u32 {
     let mut score = 0;
-    for (theirs, yours) in guide.iter() {
+    for (theirs, yours, _) in guide.iter() {
         score += yours.versus(theirs);
     }
     score
 }
 
-pub fn part2(input: Parsed) -> u32 {
-    let guide = part2_parse(input);
+pub fn part2(guide: Parsed) -> u32 {
     let mut score = 0;
-    for (theirs, outcome) in guide.iter() {
+    for (theirs, _, outcome) in guide.iter() {
         let yours = outcome.choose(theirs);
         score += yours.versus(theirs);
     }
     score
 }
 
-enum Move {
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Model<'input> = Parsed;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
+pub enum Move {
     Rock,
     Paper,
     Scissors,
@@ -87,7 +97,7 @@ impl From<&str> for Move {
     }
 }
 
-enum Outcome {
+pub enum Outcome {
     Win,
     Lose,
     Draw,
@@ -1,21 +1,33 @@
+use crate::{AocError, Result, Solution};
 use std::{
     cmp::Ordering::{Equal, Greater, Less},
     collections::VecDeque,
 };
 
-type Parsed = String;
+type Parsed = Vec<i64>;
 
-pub fn parse(input: String) -> Parsed {
-    input
-}
-
-pub fn part1(input: Parsed) -> i64 {
+pub fn parse(input: String) -> Result<Parsed> {
     // all ze numbaz
-    let nums: Vec<i64> = input
+    let nums = input
         .lines()
-        .map(|line| line.parse::<i64>().unwrap())
-        .collect();
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse::<i64>()
+                .map_err(|_| AocError::parse_line(i, "expected a number"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the grove coordinates are counted from the 0
+    if !nums.contains(&0) {
+        return Err(AocError::parse_line(
+            0,
+            "expected one of the numbers to be 0",
+        ));
+    }
+    Ok(nums)
+}
 
+pub fn part1(nums: Parsed) -> i64 {
     // in a 4 quart mixing bowl...
     let mut bowl: VecDeque<(usize, i64)> = nums.clone().into_iter().enumerate().collect();
 
@@ -61,12 +73,8 @@ pub fn part1(input: Parsed) -> i64 {
     x + y + z
 }
 
-pub fn part2(input: Parsed) -> i64 {
-    // all ze numbaz
-    let nums: Vec<i64> = input
-        .lines()
-        .map(|line| 811589153 * line.parse::<i64>().unwrap())
-        .collect();
+pub fn part2(nums: Parsed) -> i64 {
+    let nums: Vec<i64> = nums.into_iter().map(|num| 811589153 * num).collect();
 
     // in a 4 quart mixing bowl...
     let mut bowl: VecDeque<(usize, i64)> = nums.clone().into_iter().enumerate().collect();
@@ -117,6 +125,26 @@ pub fn part2(input: Parsed) -> i64 {
     x + y + z
 }
 
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Model<'input> = Parsed;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
 // fn print(bowl: &VecDeque<(usize, i64)>) -> String {
 //     format!("{:?}", bowl.iter().map(|n| n.1).collect::<Vec<i64>>())
 // }
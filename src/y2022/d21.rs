@@ -1,9 +1,48 @@
-use std::collections::{HashMap, VecDeque};
+use crate::{AocError, Result, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 type Parsed = String;
 
-pub fn parse(input: String) -> Parsed {
-    input
+/// Check every monkey yells a number or a formula of monkeys that exist, and that `root` yells a
+/// formula.  The parts borrow the names as they go, so they split the lines up themselves.
+pub fn parse(input: String) -> Result<Parsed> {
+    let mut names = HashSet::new();
+    let mut formulas = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let name = match words[..] {
+            [name, n] if n.parse::<i128>().is_ok() => name.strip_suffix(':'),
+            [name, a, "+" | "-" | "*" | "/", b] => {
+                formulas.push((i, [a, b]));
+                name.strip_suffix(':')
+            }
+            _ => None,
+        };
+        let Some(name) = name else {
+            return Err(AocError::parse_line(
+                i,
+                "expected a monkey like `root: pppw + sjmn` or `dbpl: 5`",
+            ));
+        };
+        names.insert(name);
+    }
+
+    for (i, operands) in &formulas {
+        if let Some(missing) = operands.iter().find(|name| !names.contains(*name)) {
+            return Err(AocError::parse_line(
+                *i,
+                format!("expected a monkey called `{missing}`"),
+            ));
+        }
+    }
+    if !input
+        .lines()
+        .any(|line| line.starts_with("root:") && line.split_whitespace().count() == 4)
+    {
+        return Err(AocError::parse_line(0, "expected `root` to yell a formula"));
+    }
+
+    Ok(input)
 }
 
 pub fn part1(input: Parsed) -> i128 {
@@ -213,6 +252,26 @@ pub fn part2(input: Parsed) -> String {
     format!("{} = {}", a_val.stringify(&vals), b_val.stringify(&vals))
 }
 
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Model<'input> = Parsed;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model).to_string())
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
 type Name<'a> = &'a str;
 type Operator = char;
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,711 @@
+use crate::{
+    geom::{self, Dir, Rot},
+    grid::Grid,
+    vis::{self, Color, Rgb, Visualizer},
+    AocError, Result, Solution,
+};
+use itertools::Itertools;
+use nom::{
+    character::complete::{self, one_of},
+    combinator::{all_consuming, map},
+    multi::many1,
+    sequence::pair,
+    IResult,
+};
+use std::collections::HashMap;
+
+type Parsed = (String, Vec<Step>);
+
+/// Cell colors for image exports.
+const PALETTE: &[(char, Rgb)] = &[
+    ('.', Rgb(40, 40, 48)),
+    ('#', Rgb(204, 204, 204)),
+    ('█', Rgb(188, 63, 188)),
+    ('X', Rgb(205, 49, 49)),
+    ('<', Rgb(229, 229, 16)),
+    ('>', Rgb(229, 229, 16)),
+    ('^', Rgb(229, 229, 16)),
+    ('v', Rgb(229, 229, 16)),
+];
+
+pub fn parse(input: String) -> Result<Parsed> {
+    let Some((map_str, path)) = input.split_once("\n\n") else {
+        return Err(AocError::parse_at(
+            &input,
+            "",
+            "expected the map, a blank line, then the path",
+        ));
+    };
+
+    for (i, line) in map_str.lines().enumerate() {
+        if let Some(column) = line.chars().position(|c| !" .#".contains(c)) {
+            return Err(AocError::Parse {
+                line: i + 1,
+                column: column + 1,
+                msg: "expected a map of ` `, `.` and `#`".into(),
+            });
+        }
+        // every row needs somewhere to wrap around to in part 1
+        if line.trim().is_empty() {
+            return Err(AocError::parse_line(i, "expected a row of the map"));
+        }
+    }
+    if !map_str.contains('.') {
+        return Err(AocError::parse_line(0, "expected an open tile to start on"));
+    }
+
+    let path_line = map_str.lines().count() + 1;
+    let steps = steps(path)
+        .ok_or_else(|| AocError::parse_line(path_line, "expected a path like `10R5L5`"))?;
+
+    Ok((map_str.to_string(), steps))
+}
+
+pub fn part1((map_str, steps): Parsed) -> usize {
+    let map = Map::new(&map_str);
+
+    let (mut pos, mut dir) = map.start_pos();
+
+    for step in &steps {
+        pos = map.step(&pos, step);
+        dir = step.0;
+    }
+
+    1000 * (pos.y + 1) + 4 * (pos.x + 1) + score(dir)
+}
+
+pub fn part2((map_str, steps): Parsed) -> Result<usize> {
+    let map = Map::new(&map_str);
+
+    let (mut pos, mut dir) = map.start_pos();
+
+    for step in &steps {
+        (pos, dir) = map.step2(pos, dir, step)?;
+    }
+
+    Ok(1000 * (pos.y + 1) + 4 * (pos.x + 1) + score(dir))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
+}
+
+type Step = (Dir, char, i32);
+
+/// The path's steps, each with the direction it ends up facing, or `None` if it doesn't parse.
+fn steps(path_str: &str) -> Option<Vec<Step>> {
+    fn parse(moves: &str) -> IResult<&str, Vec<Step>> {
+        let mut dir = Dir::Up; // not to worry; first step will turn this to the right
+        let steps = all_consuming(many1(map(
+            pair(one_of("LR"), complete::i32),
+            |pair: (char, i32)| {
+                dir = turn(dir, pair.0);
+                (dir, pair.0, pair.1)
+            },
+        )))(moves);
+        steps
+    }
+
+    // prefix with R just to make it more convenient to parse in pairs
+    let prefixed = format!("R{}", path_str.trim());
+    parse(&prefixed).ok().map(|(_, steps)| steps)
+}
+
+type Point = geom::Point<usize>;
+
+type Pos = (Point, Dir);
+
+/// The first and last non-void cells along a row or column.
+type Span = (usize, usize);
+
+#[derive(Debug)]
+struct Map {
+    grid: Grid<Cell>,
+    /// The min and max bounds where non-void cells lie.  Used for wrapping around when stepping
+    /// into the void.  (row_bounds, col_bounds)
+    bounds: (Vec<Span>, Vec<Span>),
+    /// if you enter void from key point going dir, teleport to value point facing dir
+    net_portals: HashMap<(Point, Dir), (Point, Dir)>,
+}
+
+impl Map {
+    fn new(map_str: &str) -> Self {
+        let width = map_str.lines().map(str::len).max().unwrap_or(0) + 4;
+        let height = map_str.lines().count() + 5;
+        Self::new_visualized(map_str, &mut *vis::open(width as u32, height as u32, 14))
+    }
+
+    /// Build the map, animating the search for the cube net's portals.
+    fn new_visualized(map_str: &str, vis: &mut dyn Visualizer) -> Self {
+        vis.palette(PALETTE);
+        // the lines in the input aren't all the same number of characters 🤦 but the padding
+        // parses as void
+        let grid: Grid<Cell> = Grid::parse(map_str);
+
+        // calculate bounds (used in part 1)
+
+        let mut bounds = (vec![], vec![]);
+        for row in grid.rows() {
+            let min_bound = row.iter().position(|n| n != &Cell::Void).unwrap();
+            let max_bound = row
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|n| n.1 != &Cell::Void)
+                .unwrap()
+                .0;
+            bounds.0.push((min_bound, max_bound));
+        }
+        for column in grid.columns() {
+            let (min_bound, max_bound) = column
+                .enumerate()
+                .filter(|n| n.1 != &Cell::Void)
+                .map(|n| n.0)
+                .minmax()
+                .into_option()
+                .unwrap_or((grid.height(), 0));
+            bounds.1.push((min_bound, max_bound));
+        }
+
+        use Dir::*;
+
+        // let seam_start = (Point::new(50, 100), Dir::Left, Dir::Up); // for the real input
+
+        let mut net_portals = HashMap::new();
+
+        /*
+
+                ...#
+                .#..
+                #...
+                ....
+        ...#.......#
+        ........#...
+        ..#....#....
+        ..........#.
+                ...#....
+                .....#..
+                .#......
+                ......#.
+
+                */
+
+        // zip the seam back together
+
+        let cell = |p: Point, dx: isize, dy: isize| -> &Cell {
+            grid.offset(p, dx, dy).map_or(&Cell::Void, |q| &grid[q])
+        };
+
+        type Kernel<'a> = [[&'a Cell; 3]; 3];
+
+        #[rustfmt::skip]
+        let kernel = |p: Point| -> Kernel {[
+            [cell(p, -1, -1), cell(p, 0, -1), cell(p, 1, -1)],
+            [cell(p, -1,  0), cell(p, 0,  0), cell(p, 1,  0)],
+            [cell(p, -1,  1), cell(p, 0,  1), cell(p, 1,  1)],
+        ]};
+
+        // Some(p) if the point at the center of a kernel is where a turn should take place because
+        // it's a corner, either concave or convex, along the cube net's seam. If no turn should
+        // take place, returns None.
+        let corner = |k: Kernel| -> Option<(Dir, Dir)> {
+            // a kernel contains a corner if one of the following is true:
+            //    a: if there are 5 voids in in the kernel, center is a convex corner
+            //    b: if there is 1 void in the kernel, center is a concave corner
+            use Dir::*;
+
+            let corners = [
+                (k[0][0], (Left, Up)),
+                (k[0][2], (Right, Up)),
+                (k[2][0], (Left, Down)),
+                (k[2][2], (Right, Down)),
+            ];
+
+            let voids = k.iter().flatten().filter(|&p| *p == &Cell::Void).count();
+
+            match voids {
+                // concave corner
+                1 => Some(corners.iter().find(|(p, _)| *p == &Cell::Void).unwrap().1),
+                // convex corner
+                5 => Some(corners.iter().find(|(p, _)| *p != &Cell::Void).unwrap().1),
+                // no corner
+                _ => None,
+            }
+        };
+
+        // Find all the concave right angles in the cube net.
+        let zippers = || -> Vec<(Point, (Dir, Dir))> {
+            let mut points = vec![];
+            for y in 1..(grid.height() - 1) {
+                for x in 1..(grid.width() - 1) {
+                    let k = kernel(Point::new(x, y));
+                    if let Some(c) = corner(k) {
+                        points.push((Point::new(x, y), c));
+                    }
+                }
+            }
+            points
+        };
+
+        // use each concave right angle as a starting point
+        let start_points = zippers();
+
+        let mut dirs;
+        let mut points;
+        let mut entdirs;
+        let mut exdirs;
+        let mut turning;
+
+        let height = map_str.lines().count() as i32 + 5;
+
+        let print_grid = |i: i32,
+                          points: &[Point; 2],
+                          dirs: &[Dir; 2],
+                          net_portals: &HashMap<(Point, Dir), (Point, Dir)>,
+                          vis: &mut dyn Visualizer| {
+            vis.begin_frame();
+
+            vis.draw_str(0, height - 4, &format!("iteration {}", i), Color::Default);
+            vis.draw_str(0, height - 3, "█ - portal", Color::Magenta);
+            vis.draw_str(0, height - 2, "X - concave corner", Color::Red);
+            vis.draw_str(
+                0,
+                height - 1,
+                "<v^> - agent travel direction",
+                Color::Yellow,
+            );
+            let starts: Vec<String> = start_points
+                .iter()
+                .map(|p| format!("{} {} {}", p.0, <&str>::from(p.1 .0), <&str>::from(p.1 .1)))
+                .collect();
+            vis.draw_str(0, height, starts.join(" / ").as_str(), Color::Default);
+
+            for (p, cell) in grid.iter() {
+                let x = p.x as i32;
+                let y = p.y as i32;
+
+                if net_portals.keys().any(|k| k.0 == p) {
+                    vis.draw_str(x, y, "█", Color::Magenta);
+                } else if start_points.iter().any(|c| c.0 == p) {
+                    vis.draw_str(x, y, "X", Color::Red);
+                } else if points[0] == p {
+                    vis.draw_str(x, y, dirs[0].into(), Color::Yellow);
+                } else if points[1] == p {
+                    vis.draw_str(x, y, dirs[1].into(), Color::Yellow);
+                } else {
+                    vis.draw_str(x, y, cell.into(), Color::Default);
+                }
+            }
+
+            vis.end_frame();
+        };
+
+        let mut i = 0;
+        for start in &start_points {
+            let mut ii = 0;
+
+            dirs = [start.1 .0, start.1 .1];
+            entdirs = [start.1 .1.flip(), start.1 .0.flip()];
+            exdirs = [start.1 .1, start.1 .0];
+            points = [start.0 + dirs[0], start.0 + dirs[1]];
+            turning = [false, false];
+
+            loop {
+                // turning occupies one iteration, since the corner is attached to two other points
+                i += 1;
+                ii += 1;
+
+                if vis.quit() {
+                    break;
+                }
+
+                // if we reached an already-cached state, we're done
+                if net_portals.contains_key(&(points[0], exdirs[0]))
+                    && net_portals.contains_key(&(points[1], exdirs[1]))
+                {
+                    break;
+                }
+
+                // check for a turn
+                let kernels: [Kernel; 2] = points.map(kernel);
+
+                let corners: [Option<(Dir, Dir)>; 2] = kernels.map(corner);
+
+                if vis.enabled() {
+                    print_grid(i, &points, &dirs, &net_portals, vis);
+                }
+
+                net_portals.insert((points[0], exdirs[0]), (points[1], entdirs[1]));
+                net_portals.insert((points[1], exdirs[1]), (points[0], entdirs[0]));
+
+                // let saved_points = points.clone();
+
+                // either turn or move
+                for agent_idx in 0..=1 {
+                    if turning[agent_idx] {
+                        turning[agent_idx] = false;
+                    } else if let Some(corner) = corners[agent_idx] {
+                        let new_dir = match dirs[agent_idx] {
+                            Up => corner.0,
+                            Right => corner.1,
+                            Down => corner.0,
+                            Left => corner.1,
+                        };
+                        let rot_dir: Rot = Rot::between(dirs[agent_idx], new_dir).unwrap();
+                        dirs[agent_idx] = new_dir;
+                        entdirs[agent_idx] = entdirs[agent_idx].rot(rot_dir);
+                        exdirs[agent_idx] = exdirs[agent_idx].rot(rot_dir);
+                        turning[agent_idx] = true;
+                    }
+                    if !turning[agent_idx] {
+                        points[agent_idx] += dirs[agent_idx];
+                    }
+                }
+
+                if turning[0] {
+                    net_portals.insert((points[0], exdirs[0]), (points[1], entdirs[1]));
+                }
+
+                if turning[1] {
+                    net_portals.insert((points[1], exdirs[1]), (points[0], entdirs[0]));
+                }
+
+                if turning[0] && turning[1] {
+                    break;
+                }
+
+                if ii > 1 && points[0] == points[1] {
+                    // reached the end of the seam
+                    break;
+                }
+            }
+        }
+
+        Self {
+            grid,
+            bounds,
+            net_portals,
+        }
+    }
+
+    /// Find the starting point on the map. (x, y)
+    fn start_pos(&self) -> Pos {
+        (self.grid.position(|n| n == &Cell::Open).unwrap(), Dir::Up)
+    }
+
+    fn step(&self, cur: &Point, step: &Step) -> Point {
+        // print!("walk {step:?} start {cur:?}",);
+
+        let mut cur = *cur;
+        for _i in 1..=step.2 {
+            cur = self.next_point(&cur, &step.0);
+        }
+
+        cur
+    }
+
+    fn step2(&self, cur: Point, dir: Dir, step: &Step) -> Result<(Point, Dir)> {
+        // print!("walk {step:?} start {cur:?}",);
+
+        let mut cur = cur;
+        let mut dir = dir;
+
+        dir = turn(dir, step.1);
+
+        for _i in 1..=step.2 {
+            (cur, dir) = self.next_point2(&cur, &dir)?;
+        }
+
+        Ok((cur, dir))
+    }
+
+    fn next_point(&self, cur: &Point, dir: &Dir) -> Point {
+        match dir {
+            Dir::Up => {
+                let up_y = (self.grid.height() + cur.y - 1) % self.grid.height();
+                match self.grid[Point::new(cur.x, up_y)] {
+                    Cell::Open => Point::new(cur.x, up_y),
+                    Cell::Wall => *cur,
+                    Cell::Void => {
+                        let p = Point::new(cur.x, self.bounds.1[cur.x].1);
+                        if self.grid[p] == Cell::Open {
+                            p
+                        } else {
+                            *cur
+                        }
+                    }
+                }
+            }
+            Dir::Right => {
+                let right_x = (cur.x + 1) % self.grid.width();
+                match self.grid[Point::new(right_x, cur.y)] {
+                    Cell::Open => Point::new(right_x, cur.y),
+                    Cell::Wall => *cur,
+                    Cell::Void => {
+                        let p = Point::new(self.bounds.0[cur.y].0, cur.y);
+                        if self.grid[p] == Cell::Open {
+                            p
+                        } else {
+                            *cur
+                        }
+                    }
+                }
+            }
+            Dir::Down => {
+                let down_y = (cur.y + 1) % self.grid.height();
+                match self.grid[Point::new(cur.x, down_y)] {
+                    Cell::Open => Point::new(cur.x, down_y),
+                    Cell::Wall => *cur,
+                    Cell::Void => {
+                        let p = Point::new(cur.x, self.bounds.1[cur.x].0);
+                        if self.grid[p] == Cell::Open {
+                            p
+                        } else {
+                            *cur
+                        }
+                    }
+                }
+            }
+            Dir::Left => {
+                let left_x = (self.grid.width() + cur.x - 1) % self.grid.width();
+                match self.grid[Point::new(left_x, cur.y)] {
+                    Cell::Open => Point::new(left_x, cur.y),
+                    Cell::Wall => *cur,
+                    Cell::Void => {
+                        let p = Point::new(self.bounds.0[cur.y].1, cur.y);
+                        if self.grid[p] == Cell::Open {
+                            p
+                        } else {
+                            *cur
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn next_point2(&self, cur: &Point, dir: &Dir) -> Result<(Point, Dir)> {
+        let portal = |cur: &Point, dir: &Dir| {
+            let Some(p) = self.net_portals.get(&(*cur, *dir)) else {
+                return Err(AocError::NoSolution(format!(
+                    "tried to go {:?} from {:?} into the void and found no portal; \
+                     the map doesn't fold into a cube",
+                    dir, cur
+                )));
+            };
+            if self.grid[p.0] == Cell::Open {
+                Ok(*p)
+            } else {
+                Ok((*cur, *dir))
+            }
+        };
+
+        let Some(next) = cur.step(*dir) else {
+            return portal(cur, dir);
+        };
+
+        let Some(cell) = self.grid.get(next) else {
+            return portal(cur, dir);
+        };
+
+        match cell {
+            Cell::Open => Ok((next, *dir)),
+            Cell::Wall => Ok((*cur, *dir)),
+            Cell::Void => portal(cur, dir),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Cell {
+    Open,
+    Wall,
+    Void,
+}
+
+impl From<Dir> for &str {
+    fn from(value: Dir) -> Self {
+        match value {
+            Dir::Up => "^",
+            Dir::Right => ">",
+            Dir::Down => "v",
+            Dir::Left => "<",
+        }
+    }
+}
+
+fn turn(dir: Dir, lr: char) -> Dir {
+    if lr == 'L' {
+        dir.ccw()
+    } else {
+        dir.cw()
+    }
+}
+
+fn score(dir: Dir) -> usize {
+    match dir {
+        Dir::Down => 1,
+        Dir::Left => 2,
+        Dir::Right => 0,
+        Dir::Up => 3,
+    }
+}
+
+impl From<char> for Cell {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Cell::Open,
+            '#' => Cell::Wall,
+            _ => Cell::Void,
+        }
+    }
+}
+
+impl From<&Cell> for &str {
+    fn from(value: &Cell) -> Self {
+        match value {
+            Cell::Open => ".",
+            Cell::Wall => "#",
+            Cell::Void => " ",
+        }
+    }
+}
+
+#[cfg(test)]
+mod vis_tests {
+    use super::*;
+
+    const SAMPLE_MAP: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.";
+
+    #[test]
+    fn day22_portals_visualized() {
+        let mut rec = vis::Recorder::new(20, 17, 14);
+        Map::new_visualized(SAMPLE_MAP, &mut rec);
+
+        assert_eq!(rec.frames.len(), 27);
+        let first = rec.frames[0].to_string();
+        assert_eq!(first.lines().nth(13), Some("iteration 1"));
+        let net: String = first.lines().take(12).collect();
+        assert_eq!(net.matches('X').count(), 3);
+
+        // by the end, the whole seam has portals
+        let last = rec.frames.last().unwrap();
+        assert!(last.to_string().starts_with("        ████\n"));
+        assert_eq!(last.get(8, 0), Some(('█', Color::Magenta)));
+        assert_eq!(last.get(8, 4), Some(('X', Color::Red)));
+    }
+}
+
+// #[cfg(test)]
+// mod tests {
+//     use super::*;
+//
+//     const REAL: &str = include_str!("../input/2022/day22.txt");
+//     const EX: &str = "        ...#
+//         .#..
+//         #...
+//         ....
+// ...#.......#
+// ........#...
+// ..#....#....
+// ..........#.
+//         ...#....
+//         .....#..
+//         .#......
+//         ......#.
+//
+// 10R5L5R10L4R5L5";
+//
+//     const EX2: &str = "        ...#
+//         .#..
+//         #...
+//         ....
+// ...#.......#
+// ........#...
+// ..#....#....
+// ..........#.
+//         ...#....
+//         .....#..
+//         .#......
+//         ......#.
+//
+// 1R0";
+//     const EX3: &str = "        ...#
+//         .#..
+//         #...
+//         ....
+// ...#.......#
+// ........#...
+// ..#....#....
+// ..........#.
+//         ...#....
+//         .....#..
+//         .#......
+//         ......#.
+//
+// 1L1L1L1L0";
+//     const SAMPLE_MAP: &str = "        ...#
+//         .#..
+//         #...
+//         ....
+// ...#.......#
+// ........#...
+// ..#....#....
+// ..........#.
+//         ...#....
+//         .....#..
+//         .#......
+//         ......#.";
+//
+//     #[test]
+//     fn day22_part1_test() {
+//         assert_eq!(part1_solve(EX), 6032);
+//         assert_eq!(part1_solve(EX2), 1041);
+//         assert_eq!(part1_solve(EX3), 1036);
+//         assert_eq!(part1_solve(REAL), 146092);
+//     }
+//
+//     #[test]
+//     fn day22_part2_test() {
+//         // assert_eq!(part2_solve(EX), 5031);
+//         // assert_eq!(part2_solve(&format!("{}\n\n{}", SAMPLE_MAP, "0R1L1R1L1R1L1")), 1000 * 2 + 4 * 9 + Dir::Right.score());
+//         // assert_eq!(part2_solve(&format!("{}\n\n{}", SAMPLE_MAP, "0L1R0L1R0")), 1000 * 1 + 4 * 9 + Dir::Right.score());
+//         // assert_eq!(part2_solve(&format!("{}\n\n{}", SAMPLE_MAP, "0L0L2R4L3")), 1000 * 12 + 4 * 12 + Dir::Up.score());
+//         // assert_eq!(part2_solve(&format!("{}\n\n{}", SAMPLE_MAP, "0L0L2R4L2R1")), 1000 * 12 + 4 * 13 + Dir::Up.score());
+//         assert_eq!(
+//             part2_solve(&format!("{}\n\n{}", SAMPLE_MAP, "0L0L2R4L3R1R1")),
+//             1000 * 8 + 4 * 1 + Dir::Right.score()
+//         );
+//     }
+//     #[test]
+//     fn day22_part2_real() {
+//         assert_eq!(part2_solve(REAL), 5031);
+//     }
+// }
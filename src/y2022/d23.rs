@@ -1,26 +1,45 @@
-#[cfg(feature = "visualize")]
-use console_engine::{ConsoleEngine, KeyCode};
-use derive_more::{Add, AddAssign, Sub, SubAssign};
+use crate::{
+    geom::{Bounds, Dir, Point},
+    sparse::SparseGrid,
+    vis::{self, Color, Rgb, Visualizer},
+    AocError, Result, Solution,
+};
 use std::{array::IntoIter, collections::HashMap, fmt::Display, iter::Cycle};
 
 type Parsed = Grove;
 
-pub fn parse(input: String) -> Parsed {
+/// Cell colors for image exports.
+const PALETTE: &[(char, Rgb)] = &[('#', Rgb(13, 188, 121)), ('.', Rgb(20, 30, 24))];
+
+pub fn parse(input: String) -> Result<Parsed> {
+    for (i, line) in input.lines().enumerate() {
+        if let Some(column) = line.chars().position(|c| c != '#' && c != '.') {
+            return Err(AocError::Parse {
+                line: i + 1,
+                column: column + 1,
+                msg: "expected a map of `#` elves and `.` ground".into(),
+            });
+        }
+    }
+    if !input.contains('#') {
+        return Err(AocError::parse_line(0, "expected at least one elf"));
+    }
+
     let grid = input
         .lines()
         .enumerate()
         .flat_map(|(y, line)| {
             line.chars().enumerate().filter_map(move |(x, c)| {
                 if c == '#' {
-                    Some((Point(x as i32, y as i32), Elf { proposal: None }))
+                    Some((Point::new(x as i32, y as i32), Elf { proposal: None }))
                 } else {
                     None
                 }
             })
         })
-        .collect::<HashMap<Point, Elf>>();
+        .collect::<SparseGrid<Elf>>();
 
-    Grove::new(grid)
+    Ok(Grove::new(grid))
 }
 
 pub fn part1(grove: Parsed) -> i32 {
@@ -33,69 +52,61 @@ fn part1_solve(grove: Parsed, rounds: usize) -> i32 {
 
     // let area = grove.grid.len() * grove.grid[0].len();
     let bounds = grove.bounding_box();
-    let area = bounds.width * bounds.height;
+    let area = bounds.width() * bounds.height();
     let elf_count = grove.grid.len() as i32;
 
     area - elf_count
 }
 
 pub fn part2(grove: Parsed) -> i32 {
+    part2_solve(grove, &mut *vis::open(140, 140, 250))
+}
+
+fn part2_solve(grove: Parsed, vis: &mut dyn Visualizer) -> i32 {
     let mut grove = grove;
+    vis.palette(PALETTE);
     // let (rounds, _) = grove.into_iter().enumerate().find(|(_, p)| !p).unwrap();
     // rounds + 1
 
-    #[cfg(feature = "visualize")]
-    let width = 140;
-    #[cfg(feature = "visualize")]
-    let height = 140;
-    #[cfg(feature = "visualize")]
-    let fps = 250;
-    #[cfg(feature = "visualize")]
-    let mut engine = ConsoleEngine::init(width, height, fps).unwrap();
-
-    #[cfg(feature = "visualize")]
-    let print_grid = |round: i32, grove: &Grove, engine: &mut ConsoleEngine| {
-        engine.wait_frame();
-        engine.clear_screen();
-
-        engine.print(0, 0, &format!("Round {}", round));
-        engine.print(0, 2, &format!("{grove}"));
-
-        engine.draw();
+    let print_grid = |round: i32, grove: &Grove, vis: &mut dyn Visualizer| {
+        vis.begin_frame();
+        vis.status(&format!("round {round}"));
+        vis.draw_str(0, 0, &format!("Round {}", round), Color::Default);
+        vis.draw_str(0, 2, &format!("{grove}"), Color::Green);
+        vis.end_frame();
     };
 
     let mut rounds = 1;
     while let Some(true) = grove.next() {
-        #[cfg(feature = "visualize")]
-        if engine.is_key_pressed(KeyCode::Char('q')) {
-            break;
+        if vis.enabled() {
+            if vis.quit() {
+                break;
+            }
+            print_grid(rounds, &grove, vis);
         }
-
-        #[cfg(feature = "visualize")]
-        print_grid(rounds, &grove, &mut engine);
         rounds += 1;
     }
 
-    println!("{grove}",);
-
     rounds
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Add, AddAssign, Sub, SubAssign)]
-struct Point(i32, i32);
+pub struct Day23;
 
-impl Point {
-    fn north() -> Self {
-        Point(0, -1)
-    }
-    fn south() -> Self {
-        Point(0, 1)
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Model<'input> = Parsed;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
     }
-    fn east() -> Self {
-        Point(1, 0)
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
     }
-    fn west() -> Self {
-        Point(-1, 0)
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
     }
 }
 
@@ -150,26 +161,20 @@ impl<'elf> Survey<'elf> {
     }
 }
 
-struct BoundingBox {
-    width: i32,
-    height: i32,
-    origin: Point,
-}
-
 pub struct Grove {
-    grid: HashMap<Point, Elf>,
-    directions: Cycle<IntoIter<(char, Point), 4>>,
+    grid: SparseGrid<Elf>,
+    directions: Cycle<IntoIter<(char, Dir), 4>>,
 }
 
 impl Grove {
-    fn new(grid: HashMap<Point, Elf>) -> Self {
+    fn new(grid: SparseGrid<Elf>) -> Self {
         Self {
             grid,
             directions: [
-                ('n', Point::north()),
-                ('s', Point::south()),
-                ('w', Point::west()),
-                ('e', Point::east()),
+                ('n', Dir::Up),
+                ('s', Dir::Down),
+                ('w', Dir::Left),
+                ('e', Dir::Right),
             ]
             .into_iter()
             .cycle(),
@@ -177,45 +182,29 @@ impl Grove {
     }
 
     fn survey(&self, loc: Point) -> Survey {
-        let n = loc + Point::north();
-        let ne = loc + Point::north() + Point::east();
-        let e = loc + Point::east();
-        let se = loc + Point::south() + Point::east();
-        let s = loc + Point::south();
-        let sw = loc + Point::south() + Point::west();
-        let w = loc + Point::west();
-        let nw = loc + Point::north() + Point::west();
+        let n = loc + Dir::Up;
+        let ne = loc + Dir::Up + Dir::Right;
+        let e = loc + Dir::Right;
+        let se = loc + Dir::Down + Dir::Right;
+        let s = loc + Dir::Down;
+        let sw = loc + Dir::Down + Dir::Left;
+        let w = loc + Dir::Left;
+        let nw = loc + Dir::Up + Dir::Left;
 
         Survey {
-            n: self.grid.get(&n),
-            ne: self.grid.get(&ne),
-            e: self.grid.get(&e),
-            se: self.grid.get(&se),
-            s: self.grid.get(&s),
-            sw: self.grid.get(&sw),
-            w: self.grid.get(&w),
-            nw: self.grid.get(&nw),
+            n: self.grid.get(n),
+            ne: self.grid.get(ne),
+            e: self.grid.get(e),
+            se: self.grid.get(se),
+            s: self.grid.get(s),
+            sw: self.grid.get(sw),
+            w: self.grid.get(w),
+            nw: self.grid.get(nw),
         }
     }
 
-    fn bounding_box(&self) -> BoundingBox {
-        let mut minx = i32::MAX;
-        let mut maxx = i32::MIN;
-        let mut miny = i32::MAX;
-        let mut maxy = i32::MIN;
-
-        for point in self.grid.keys() {
-            minx = minx.min(point.0);
-            maxx = maxx.max(point.0);
-            miny = miny.min(point.1);
-            maxy = maxy.max(point.1);
-        }
-
-        BoundingBox {
-            width: maxx - minx + 1,
-            height: maxy - miny + 1,
-            origin: Point(minx, miny),
-        }
+    fn bounding_box(&self) -> Bounds {
+        self.grid.bounds().unwrap()
     }
 }
 
@@ -231,16 +220,16 @@ impl Iterator for Grove {
         let mut elf_moved = false;
         let mut moves = vec![];
         // first half
-        for (point, _) in self.grid.iter() {
+        for point in self.grid.points() {
             let mut dirs = self.directions.clone();
-            let survey = self.survey(*point);
+            let survey = self.survey(point);
             if !survey.is_empty() {
                 for _ in 0..4 {
                     let (dirname, dir) = dirs.next().unwrap();
                     if survey.is_empty_in_dir(dirname) {
                         moves.push(Move {
-                            from: *point,
-                            to: *point + dir,
+                            from: point,
+                            to: point + dir,
                         });
                         break; // done with this elf
                     }
@@ -262,7 +251,7 @@ impl Iterator for Grove {
             let mov_count = histo.get(&mov.to);
             if let Some(1) = mov_count {
                 // remove the elf at mov.from from self.grid and reinsert it at mov.to
-                if let Some(elf) = self.grid.remove(&mov.from) {
+                if let Some(elf) = self.grid.remove(mov.from) {
                     self.grid.insert(mov.to, elf);
                     elf_moved = true;
                 }
@@ -279,19 +268,40 @@ impl Iterator for Grove {
 impl Display for Grove {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         const PAD: i32 = 3;
-        let bounds = self.bounding_box();
-        for y in (bounds.origin.1 - PAD)..=(bounds.origin.1 + bounds.height + PAD) {
-            for x in (bounds.origin.0 - PAD)..=(bounds.origin.0 + bounds.width + PAD) {
-                let cell = self.grid.get(&Point(x, y));
-                let symbol = match cell {
-                    Some(_) => '#',
-                    None => '.',
-                };
-                write!(f, "{symbol}")?;
-            }
-            writeln!(f)?;
+        let grove = self.grid.render(PAD, |cell| match cell {
+            Some(_) => '#',
+            None => '.',
+        });
+        write!(f, "{grove}")
+    }
+}
+
+#[cfg(test)]
+mod vis_tests {
+    use super::*;
+
+    const EX2: &str = "....#..\n\
+                       ..###.#\n\
+                       #...#.#\n\
+                       .#...##\n\
+                       #.###..\n\
+                       ##.#.##\n\
+                       .#..#..";
+
+    #[test]
+    fn day23_part2_visualized() {
+        let mut rec = vis::Recorder::new(40, 40, 250);
+        assert_eq!(part2_solve(parse(EX2.to_string()).unwrap(), &mut rec), 20);
+
+        // one frame for every round where an elf moved
+        assert_eq!(rec.frames.len(), 19);
+        for (round, frame) in rec.frames.iter().enumerate() {
+            let text = frame.to_string();
+            assert!(text.starts_with(&format!("Round {}\n\n...", round + 1)));
+            assert_eq!(text.matches('#').count(), 22);
+            assert_eq!(frame.status, format!("round {}", round + 1));
         }
-        Ok(())
+        assert_eq!(rec.frames[0].get(0, 2), Some(('.', Color::Green)));
     }
 }
 
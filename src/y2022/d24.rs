@@ -0,0 +1,443 @@
+use crate::{
+    geom::{self, Dir, Point},
+    grid::Grid,
+    vis::{self, Color, Rgb, Visualizer},
+    AocError, Result, Solution,
+};
+use itertools::Itertools;
+use pathfinding::directed::astar::astar;
+use std::fmt::Display;
+
+type Parsed = Basin;
+
+/// Cell colors for image exports.
+const PALETTE: &[(char, Rgb)] = &[
+    ('#', Rgb(90, 90, 100)),
+    ('.', Rgb(10, 20, 40)),
+    ('<', Rgb(17, 168, 205)),
+    ('>', Rgb(17, 168, 205)),
+    ('^', Rgb(17, 168, 205)),
+    ('v', Rgb(17, 168, 205)),
+    ('2', Rgb(120, 210, 235)),
+    ('3', Rgb(190, 235, 250)),
+    ('4', Rgb(255, 255, 255)),
+    ('█', Rgb(229, 229, 16)),
+];
+
+pub fn parse(input: String) -> Result<Parsed> {
+    let map = Grid::parse_with(&input, |c| "#.<>^v".contains(c).then_some(c));
+    // short lines are padded out with spaces, so they show up here too
+    if let Some(p) = map.position(Option::is_none) {
+        return Err(AocError::Parse {
+            line: p.y + 1,
+            column: p.x + 1,
+            msg: "expected a rectangle of `#`, `.` and blizzards `<>^v`".into(),
+        });
+    }
+    let map = map.map(|c| c.unwrap_or_default());
+
+    // the gaps in the top and bottom walls
+    let start = map.position(|&c| c == '.');
+    let end = map.iter().filter(|&(_, &c)| c == '.').last();
+    let walled = match (start, end) {
+        (Some(start), Some((end, _))) => start.y == 0 && end.y + 1 == map.height(),
+        _ => false,
+    };
+    if !walled {
+        return Err(AocError::parse_line(
+            0,
+            "expected a gap in the top and bottom walls",
+        ));
+    }
+
+    Ok(Basin::new(map))
+}
+
+pub fn part1(basin: Parsed) -> Result<i32> {
+    let mut basin = basin;
+
+    let basins = (0..(basin.width() * basin.height()))
+        .map(|_| {
+            basin.step();
+            basin.clone()
+        })
+        .collect_vec();
+
+    // for pathfinding, combine the grid Point with a usize representing the iteration number.
+    // this is only because the pathfinding crate's algos refuse to revisit the same point twice,
+    // but revisiting is required to solve this problem.
+    type PathPoint = (Point, usize);
+    let start = (basin.start, 0);
+    let successors = |p: &PathPoint| -> Vec<(PathPoint, i32)> {
+        // past the last minute we've simulated there's nowhere left to go
+        basins.get(p.1).map_or(vec![], |basin| {
+            basin
+                .moves(p.0)
+                .into_iter()
+                .map(|next| ((next, p.1 + 1), 1))
+                .collect()
+        })
+    };
+    let heuristic = |p: &PathPoint| {
+        let diff = basin.end - p.0;
+        diff.x + diff.y
+    };
+    let success = |p: &PathPoint| p.0 == basin.end;
+
+    let answer = astar(&start, successors, heuristic, success);
+
+    answer
+        .map(|(_, minutes)| minutes)
+        .ok_or_else(no_way_through)
+}
+
+pub fn part2(basin: Parsed) -> Result<i32> {
+    let mut vis = vis::open(basin.width() as u32, basin.height() as u32 + 1, 30);
+    part2_solve(basin, &mut *vis)
+}
+
+fn part2_solve(basin: Basin, vis: &mut dyn Visualizer) -> Result<i32> {
+    let mut basin = basin;
+    vis.palette(PALETTE);
+
+    let basins = (0..3 * (basin.width() * basin.height()))
+        .map(|_| {
+            basin.step();
+            basin.clone()
+        })
+        .collect_vec();
+
+    let print_grid = |minute: usize, player: &Point, vis: &mut dyn Visualizer| {
+        let basin = basins.get(minute).unwrap();
+        vis.begin_frame();
+        vis.status(&format!("minute {minute}"));
+        vis.draw_str(0, 0, &format!("{}", basin), Color::Cyan);
+        vis.draw_char(player.x, player.y, '█', Color::Yellow);
+        vis.end_frame();
+    };
+
+    // for pathfinding, combine the grid Point with a usize representing the iteration number.
+    // this is only because the pathfinding crate's algos refuse to revisit the same point twice,
+    // but revisiting is required to solve this problem.
+    type PathPoint = (Point, usize);
+    let successors = |p: &PathPoint| -> Vec<(PathPoint, i32)> {
+        let moves = basins.get(p.1).map_or(vec![], |basin| {
+            basin
+                .moves(p.0)
+                .into_iter()
+                .map(|next| ((next, p.1 + 1), 1))
+                .collect()
+        });
+        // println!("{moves:?}");
+        moves
+    };
+    let heuristic1 = |p: &PathPoint| {
+        let diff = basin.end - p.0;
+        diff.x + diff.y
+    };
+    let heuristic2 = |p: &PathPoint| {
+        let diff = basin.start - p.0;
+        diff.x + diff.y
+    };
+    let success1 = |p: &PathPoint| p.0 == basin.end;
+    let success2 = |p: &PathPoint| p.0 == basin.start;
+
+    // to goal
+    let start1 = (basin.start, 0);
+    let phase1 = astar(&start1, successors, heuristic1, success1).ok_or_else(no_way_through)?;
+
+    // back to start
+    let start2 = (basin.end, phase1.1 as usize);
+    let phase2 = astar(&start2, successors, heuristic2, success2).ok_or_else(no_way_through)?;
+
+    // back to goal with little elfie mcforgetful's snacks
+    let start3 = (basin.start, (phase1.1 + phase2.1) as usize);
+    let phase3 = astar(&start3, successors, heuristic1, success1).ok_or_else(no_way_through)?;
+
+    if vis.enabled() {
+        let steps = phase1
+            .0
+            .iter()
+            .chain(phase2.0.iter())
+            .chain(phase3.0.iter());
+        let mut last_step = 0;
+        for step in steps {
+            if vis.quit() {
+                break;
+            }
+            print_grid(step.1, &step.0, vis);
+            last_step = step.1;
+        }
+        let last_basin = basins.get(last_step).unwrap();
+
+        // keep the animation running for a few more steps
+        for _ in 0..14 {
+            last_step += 1;
+            print_grid(last_step, &last_basin.end, vis);
+        }
+    }
+
+    Ok(phase1.1 + phase2.1 + phase3.1)
+}
+
+fn no_way_through() -> AocError {
+    AocError::NoSolution("there's no way through the blizzards".into())
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Model<'input> = Parsed;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part1(model)
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Basin {
+    blizzards: Vec<Blizz>,
+    /// Where the blizzards are this minute.
+    nogo: Grid<bool>,
+    start: Point,
+    end: Point,
+}
+
+impl Basin {
+    /// Build the basin from a map already checked by [`parse`].
+    fn new(map: Grid<char>) -> Self {
+        let at = |p: geom::Point<usize>| Point::new(p.x as i32, p.y as i32);
+
+        // the gaps in the top and bottom walls
+        let start = map.position(|&c| c == '.').unwrap();
+        let end = map.iter().filter(|&(_, &c)| c == '.').last().unwrap().0;
+
+        let blizzards = map
+            .iter()
+            .filter_map(|(p, &c)| Blizz::try_from(&Cell::from((c, p.x as i32, p.y as i32))).ok())
+            .collect();
+
+        Self {
+            blizzards,
+            nogo: Grid::new(map.width(), map.height(), false),
+            start: at(start),
+            end: at(end),
+        }
+    }
+
+    /// Width, including the outer walls.
+    fn width(&self) -> i32 {
+        self.nogo.width() as i32
+    }
+
+    /// Height, including the outer walls.
+    fn height(&self) -> i32 {
+        self.nogo.height() as i32
+    }
+
+    fn step(&mut self) {
+        let (width, height) = (self.width(), self.height());
+        self.nogo.fill(false);
+        for blizz in self.blizzards.iter_mut() {
+            blizz.loc += blizz.dir;
+            if blizz.loc.x == 0 {
+                blizz.loc.x = width - 2;
+            }
+            if blizz.loc.y == 0 {
+                blizz.loc.y = height - 2;
+            }
+            if blizz.loc.x == width - 1 {
+                blizz.loc.x = 1;
+            }
+            if blizz.loc.y == height - 1 {
+                blizz.loc.y = 1;
+            }
+            *self.nogo.get_mut(blizz.loc).unwrap() = true;
+        }
+    }
+
+    /// Find valid moves from a given point at the current timestep.
+    fn moves(&self, from: Point) -> Vec<Point> {
+        [
+            from,                // wait
+            from + Dir::Right,
+            from + Dir::Left,
+            from + Dir::Down,
+            from + Dir::Up,
+        ]
+        .into_iter()
+        .filter(|&loc| {
+            let is_start = loc == self.start;
+            let is_end = loc == self.end;
+            // let back_to_start = from != self.start && is_start;
+            let tl_wall = loc.x == 0 || loc.y == 0;
+            let br_wall = loc.x == (self.width() - 1) || loc.y == (self.height() - 1);
+            let oob = loc.x < 0 || loc.y < 0 || loc.x > self.width() || loc.y > self.height();
+            let is_blizz = self.nogo.get(loc).copied().unwrap_or(true);
+
+            let is_good = is_end
+                || (/* !back_to_start &&*/ !is_blizz && !oob && ((is_start) || !(tl_wall || br_wall)));
+            // println!("{loc}\tis_start:\t{is_start}\tis_end:\t{is_end}\tback_to_start:\t{back_to_start}\ttl_wall:\t{tl_wall}\tbr_wall:\t{br_wall}\toob:\t{oob}\tis_blizz:\t{is_blizz}");
+
+            is_good
+        })
+        .collect()
+    }
+}
+
+impl Display for Basin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let p = Point::new(x, y);
+                let blizz: Vec<&Blizz> = self.blizzards.iter().filter(|&b| b.loc == p).collect();
+                if (y == 0 || x == 0 || y == self.height() - 1 || x == self.width() - 1)
+                    && self.start != p
+                    && self.end != p
+                {
+                    write!(f, "#")?;
+                } else if blizz.len() > 1 {
+                    write!(f, "{}", blizz.len())?;
+                } else if blizz.len() == 1 {
+                    write!(
+                        f,
+                        "{}",
+                        std::convert::Into::<char>::into(*blizz.first().unwrap())
+                    )?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Cell {
+    Wall,
+    Empty,
+    Up(Point),
+    Down(Point),
+    West(Point),
+    East(Point),
+}
+
+impl From<(char, i32, i32)> for Cell {
+    fn from((c, x, y): (char, i32, i32)) -> Self {
+        match c {
+            '#' => Cell::Wall,
+            '.' => Cell::Empty,
+            '^' => Cell::Up(Point::new(x, y)),
+            'v' => Cell::Down(Point::new(x, y)),
+            '<' => Cell::West(Point::new(x, y)),
+            '>' => Cell::East(Point::new(x, y)),
+            _ => panic!("invalid char {c}"),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Blizz {
+    loc: Point,
+    dir: Dir,
+}
+
+impl From<&Blizz> for char {
+    fn from(val: &Blizz) -> Self {
+        match val.dir {
+            Dir::Down => 'v',
+            Dir::Up => '^',
+            Dir::Right => '>',
+            Dir::Left => '<',
+        }
+    }
+}
+
+impl TryFrom<&Cell> for Blizz {
+    type Error = ();
+
+    fn try_from(value: &Cell) -> Result<Self, Self::Error> {
+        match value {
+            Cell::Wall => Err(()),
+            Cell::Empty => Err(()),
+            Cell::Up(p) => Ok(Blizz {
+                loc: *p,
+                dir: Dir::Up,
+            }),
+            Cell::Down(p) => Ok(Blizz {
+                loc: *p,
+                dir: Dir::Down,
+            }),
+            Cell::West(p) => Ok(Blizz {
+                loc: *p,
+                dir: Dir::Left,
+            }),
+            Cell::East(p) => Ok(Blizz {
+                loc: *p,
+                dir: Dir::Right,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REAL: &str = include_str!("../../input/2022/d24");
+    const EX: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn day24_part1_example() {
+        assert_eq!(part1(parse(EX.to_string()).unwrap()), Ok(18));
+    }
+    #[test]
+    fn day24_part1_real() {
+        assert_eq!(part1(parse(REAL.to_string()).unwrap()), Ok(290));
+    }
+
+    #[test]
+    fn day24_part2_example() {
+        assert_eq!(part2(parse(EX.to_string()).unwrap()), Ok(54));
+    }
+    #[test]
+    fn day24_part2_visualized() {
+        let mut rec = vis::Recorder::new(8, 7, 30);
+        assert_eq!(
+            part2_solve(parse(EX.to_string()).unwrap(), &mut rec),
+            Ok(54)
+        );
+
+        // the three trips' paths overlap where each one starts, plus 14 frames at the end
+        assert_eq!(rec.frames.len(), 54 + 3 + 14);
+        assert_eq!(rec.frames[0].get(1, 0), Some(('█', Color::Yellow)));
+        assert_eq!(rec.frames[0].get(0, 0), Some(('#', Color::Cyan)));
+        assert_eq!(rec.frames[0].status, "minute 0");
+        assert_eq!(rec.frames.last().unwrap().status, "minute 68");
+        assert_eq!(
+            rec.frames.last().unwrap().get(6, 5),
+            Some(('█', Color::Yellow))
+        );
+    }
+    #[test]
+    fn day24_part2_real() {
+        assert_eq!(part2(parse(REAL.to_string()).unwrap()), Ok(842));
+    }
+}
@@ -1,17 +1,61 @@
-type Parsed = String;
+use crate::{AocError, Result, Solution};
 
-pub fn parse(input: String) -> Parsed {
+type Parsed = Vec<i128>;
+
+/// Longest number we'll read, so that the sum stays well inside an `i128`.
+const MAX_DIGITS: usize = 50;
+
+pub fn parse(input: String) -> Result<Parsed> {
     input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if let Some(column) = line.chars().position(|c| !"=-012".contains(c)) {
+                return Err(AocError::Parse {
+                    line: i + 1,
+                    column: column + 1,
+                    msg: "expected a SNAFU digit, one of `=-012`".into(),
+                });
+            }
+            if line.is_empty() || line.len() > MAX_DIGITS {
+                return Err(AocError::parse_line(
+                    i,
+                    format!("expected a SNAFU number of 1 to {MAX_DIGITS} digits"),
+                ));
+            }
+            Ok(snafu(line))
+        })
+        .collect()
 }
 
 pub fn part1(input: Parsed) -> String {
-    ufans(input.lines().map(snafu).sum())
+    ufans(input.iter().sum())
 }
 
 pub fn part2(_input: Parsed) -> &'static str {
     "merry christmas!"
 }
 
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Model<'input> = Parsed;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model).to_string())
+    }
+}
+
 fn snafu(enc: &str) -> i128 {
     enc.chars()
         .rev()
@@ -65,7 +109,7 @@ fn ufans(num: i128) -> String {
 mod tests {
     use super::*;
 
-    const REAL: &str = include_str!("../input/d25");
+    const REAL: &str = include_str!("../../input/2022/d25");
     const EX: &str = "1=-0-2
 12111
 2=0=
@@ -144,10 +188,21 @@ mod tests {
     }
     #[test]
     fn day25_part1_example() {
-        assert_eq!(part1(EX.to_string()), "2=-1=0".to_string());
+        assert_eq!(part1(parse(EX.to_string()).unwrap()), "2=-1=0".to_string());
+    }
+    #[test]
+    fn day25_bad_input() {
+        assert_eq!(
+            parse("1=\n12x\n".to_string()).unwrap_err().to_string(),
+            "parse error at line 2, column 3: expected a SNAFU digit, one of `=-012`"
+        );
+        assert!(parse("1\n\n2".to_string()).is_err());
     }
     #[test]
     fn day25_part1_real() {
-        assert_eq!(part1(REAL.to_string()), "2-0==21--=0==2201==2".to_string());
+        assert_eq!(
+            part1(parse(REAL.to_string()).unwrap()),
+            "2-0==21--=0==2201==2".to_string()
+        );
     }
 }
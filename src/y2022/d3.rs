@@ -1,10 +1,22 @@
+use crate::{AocError, Result, Solution};
 use num_bigint::BigUint;
 use once_cell::sync::Lazy;
 
 type Parsed = String;
 
-pub fn parse(input: String) -> Parsed {
-    input
+/// Check every rucksack holds only letters.  The rest of the parsing borrows the primes, so it's
+/// left to each part.
+pub fn parse(input: String) -> Result<Parsed> {
+    for (i, line) in input.lines().enumerate() {
+        if let Some(col) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(AocError::Parse {
+                line: i + 1,
+                column: col + 1,
+                msg: "expected only letters".into(),
+            });
+        }
+    }
+    Ok(input)
 }
 
 pub fn part1(input: Parsed) -> usize {
@@ -32,7 +44,13 @@ pub fn part1(input: Parsed) -> usize {
     priority_sum
 }
 
-pub fn part2(input: Parsed) -> usize {
+pub fn part2(input: Parsed) -> Result<usize> {
+    if !input.lines().count().is_multiple_of(3) {
+        return Err(AocError::NoSolution(
+            "the rucksacks don't split into groups of three".into(),
+        ));
+    }
+
     let compartments = part2_parse(input.as_str());
 
     let mut priority_sum = 0;
@@ -53,7 +71,27 @@ pub fn part2(input: Parsed) -> usize {
         }
     }
 
-    priority_sum
+    Ok(priority_sum)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
 }
 
 // prime factor approach won't work because even u128 would be needed to hold the upper limit of
@@ -193,7 +231,18 @@ ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw"
                     .to_string()
             ),
-            70
+            Ok(70)
+        );
+    }
+
+    #[test]
+    fn bad_input() {
+        assert_eq!(
+            parse("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNq RjqzjGDLGL\n".to_string())
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 2, column 7: expected only letters"
         );
+        assert!(part2("vJrwpWtwJgWrhcsFMMfFFhFp".to_string()).is_err());
     }
 }
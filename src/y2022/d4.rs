@@ -0,0 +1,130 @@
+use crate::{intervals::IntervalSet, AocError, Result, Solution};
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::character::complete::u32 as nomu32;
+use nom::combinator::{all_consuming, map};
+use nom::multi::separated_list0;
+use nom::{sequence::separated_pair, IResult};
+
+type Parsed = Vec<(IntervalSet<u32>, IntervalSet<u32>)>;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    type ParseRange = (u32, u32);
+    type Line = (ParseRange, ParseRange);
+
+    fn range(r: &str) -> IResult<&str, ParseRange> {
+        separated_pair(nomu32, tag("-"), nomu32)(r)
+    }
+
+    fn line(line_str: &str) -> IResult<&str, Line> {
+        separated_pair(range, tag(","), range)(line_str)
+    }
+
+    fn line_to_ranges(line_str: &str) -> IResult<&str, (IntervalSet<u32>, IntervalSet<u32>)> {
+        map(line, |r| {
+            (
+                IntervalSet::from(r.0 .0..=r.0 .1),
+                IntervalSet::from(r.1 .0..=r.1 .1),
+            )
+        })(line_str)
+    }
+
+    let input = input.trim_end();
+    all_consuming(separated_list0(line_ending, line_to_ranges))(input)
+        .map(|(_, pairs)| pairs)
+        .map_err(AocError::from_nom(
+            input,
+            "expected a pair of ranges like `2-4,6-8`",
+        ))
+}
+
+pub fn part1(pairs: Parsed) -> usize {
+    pairs
+        .iter()
+        .filter(|pair| {
+            // one range fully contains the other if adding it changes nothing
+            let both = pair.0.merge(&pair.1);
+            both == pair.0 || both == pair.1
+        })
+        .count()
+}
+
+pub fn part2(pairs: Parsed) -> usize {
+    pairs
+        .iter()
+        .filter(|pair| !pair.0.intersect(&pair.1).is_empty())
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
+#[cfg(test)]
+mod day4_tests {
+    use super::*;
+
+    #[test]
+    fn part1_solve_test() {
+        assert_eq!(
+            part1(
+                parse(
+                    "2-4,6-8\n\
+                2-3,4-5\n\
+                5-7,7-9\n\
+                2-8,3-7\n\
+                6-6,4-6\n\
+                2-6,4-8"
+                        .to_string()
+                )
+                .unwrap()
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        assert_eq!(
+            part2(
+                parse(
+                    "2-4,6-8\n\
+                2-3,4-5\n\
+                5-7,7-9\n\
+                2-8,3-7\n\
+                6-6,4-6\n\
+                2-6,4-8"
+                        .to_string()
+                )
+                .unwrap()
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn bad_input() {
+        assert_eq!(
+            parse("2-4,6-8\n2-3,4-5\n5-7;7-9\n".to_string())
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 3, column 1: expected a pair of ranges like `2-4,6-8`"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{
+    vis::{self, Color, Visualizer},
+    AocError, Result, Solution,
+};
+use std::{fmt::Write, time::Duration};
+
+type Parsed = Supplies<9>;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    Supplies::parse(&input)
+}
+
+pub fn part1(supplies: Parsed) -> Result<String> {
+    part1_solve(supplies)
+}
+
+pub fn part2(supplies: Parsed) -> Result<String> {
+    part2_solve(supplies, &mut *vis::open(9 * 4, TERM_HEIGHT as u32, 60))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Model<'input> = Parsed;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part1(model)
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
+}
+
+fn part1_solve<const STACK_COUNT: usize>(mut supplies: Supplies<STACK_COUNT>) -> Result<String> {
+    supplies.rearrange_9000()?;
+
+    Ok(supplies.top_crates()?.iter().cloned().collect())
+}
+
+fn part2_solve<const STACK_COUNT: usize>(
+    mut supplies: Supplies<STACK_COUNT>,
+    vis: &mut dyn Visualizer,
+) -> Result<String> {
+    supplies.rearrange_9001(vis)?;
+
+    Ok(supplies.top_crates()?.iter().cloned().collect())
+}
+
+/// The height of the visualization, which is enough to hold the tallest any stack gets with my
+/// input.  The stacks are drawn standing on its bottom edge.
+const TERM_HEIGHT: i32 = 48;
+
+#[derive(Debug, PartialEq)]
+pub struct Supplies<const STACK_COUNT: usize> {
+    stacks: Vec<Stack>,
+    moves: Vec<Move>,
+}
+
+type Stack = Vec<char>;
+
+#[derive(Debug, PartialEq)]
+struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    /// Parse a move like `move 1 from 2 to 1`, with stacks numbered from 1 up to `stack_count`.
+    fn parse(value: &str, stack_count: usize) -> Option<Self> {
+        let stack = |s: &str| {
+            s.parse::<usize>()
+                .ok()
+                .filter(|n| (1..=stack_count).contains(n))
+                .map(|n| n - 1)
+        };
+        match value.split_whitespace().collect::<Vec<_>>()[..] {
+            ["move", count, "from", from, "to", to] => Some(Self {
+                count: count.parse().ok()?,
+                from: stack(from)?,
+                to: stack(to)?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl<const STACK_COUNT: usize> Supplies<STACK_COUNT> {
+    fn parse(input: &str) -> Result<Supplies<STACK_COUNT>> {
+        let Some((stacks_input, moves_input)) = input.split_once("\n\n") else {
+            return Err(AocError::parse_line(
+                0,
+                "expected the stacks, a blank line, then the moves",
+            ));
+        };
+
+        let mut stacks: Vec<Vec<char>> = vec![vec![]; STACK_COUNT];
+
+        for (line_idx, line) in stacks_input.lines().enumerate() {
+            let mut chars = line.chars();
+
+            chars.next(); // consume initial [
+
+            // if the first char of the line is 1, then we've reached the labels.  otherwise,
+            // document the stack items.
+            for (i, c) in chars.step_by(4).enumerate() {
+                if let 'A'..='Z' = c {
+                    let Some(stack) = stacks.get_mut(i) else {
+                        return Err(AocError::parse_line(
+                            line_idx,
+                            format!("expected at most {STACK_COUNT} stacks"),
+                        ));
+                    };
+                    // add the char to the beginning of the vec, since they're listed in reverse order
+                    stack.insert(0, c);
+                }
+            }
+        }
+
+        // the moves start after the stacks and the blank line
+        let first_move = stacks_input.lines().count() + 1;
+        let moves = moves_input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                Move::parse(line, STACK_COUNT).ok_or_else(|| {
+                    AocError::parse_line(
+                        first_move + i,
+                        format!("expected a move like `move 1 from 2 to 1`, between stacks 1 to {STACK_COUNT}"),
+                    )
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Supplies { stacks, moves })
+    }
+
+    fn rearrange_9000(&mut self) -> Result<()> {
+        for mov in &self.moves {
+            for _ in 0..mov.count {
+                let from_crate = self.stacks[mov.from].pop().ok_or_else(|| empty(mov.from))?;
+                self.stacks[mov.to].push(from_crate);
+            }
+        }
+        Ok(())
+    }
+
+    fn rearrange_9001(&mut self, vis: &mut dyn Visualizer) -> Result<()> {
+        let print_grid = |stacks: &Vec<Vec<char>>, vis: &mut dyn Visualizer| {
+            vis.begin_frame();
+
+            let mut output: Vec<String> = Vec::new();
+
+            // find the tallest stack height H
+            // loop over i from H to 0 and print the char from each stack that has a char at that i
+            // label the stacks at the bottom
+
+            let height = stacks.iter().map(|s| s.len()).max().unwrap();
+
+            for h in (0..height).rev() {
+                let mut line: Vec<String> = Vec::new();
+                for s in stacks {
+                    line.push(match s.get(h) {
+                        Some(c) => format!("[{}] ", c),
+                        None => "    ".into(),
+                    });
+                }
+                output.push(line.join(""));
+            }
+
+            // add number labels to the stacks
+            output.push((1..=stacks.len()).fold(String::new(), |mut s, n| {
+                let _ = write!(s, " {}  ", n);
+                s
+            }));
+
+            vis.draw_str(
+                0,
+                TERM_HEIGHT - (height as i32) - 2,
+                &output.join("\n"),
+                Color::Default,
+            );
+            vis.end_frame();
+        };
+
+        for mov in &self.moves {
+            let from_len = self.stacks[mov.from].len();
+            if from_len < mov.count {
+                return Err(empty(mov.from));
+            }
+
+            // reverse the elements about to be moved
+            self.stacks[mov.from][from_len - mov.count..].reverse();
+
+            for _ in 0..mov.count {
+                let from_crate = self.stacks[mov.from].pop().ok_or_else(|| empty(mov.from))?;
+                self.stacks[mov.to].push(from_crate);
+
+                if vis.enabled() {
+                    if vis.quit() {
+                        break;
+                    }
+                    print_grid(&self.stacks, vis);
+                }
+            }
+        }
+
+        // keep the final on-screen for a bit before exiting
+        vis.sleep(Duration::from_millis(2000));
+        Ok(())
+    }
+
+    fn top_crates(&self) -> Result<[char; STACK_COUNT]> {
+        let mut top_crates = [' '; STACK_COUNT];
+
+        for (i, stack) in self.stacks.iter().enumerate() {
+            top_crates[i] = *stack.last().ok_or_else(|| empty(i))?;
+        }
+
+        Ok(top_crates)
+    }
+}
+
+/// CRATERED!
+fn empty(stack: usize) -> AocError {
+    AocError::NoSolution(format!("stack {} ran out of crates", stack + 1))
+}
+
+#[cfg(test)]
+mod day5_tests {
+    use super::*;
+
+    const REAL: &str = include_str!("../../input/2022/d5");
+
+    const EX: &str = "    [D]    \n\
+                      [N] [C]    \n\
+                      [Z] [M] [P]\n\
+                       1   2   3 \n\
+                      \n\
+                      move 1 from 2 to 1\n\
+                      move 3 from 1 to 3\n\
+                      move 2 from 2 to 1\n\
+                      move 1 from 1 to 2";
+
+    fn supplies<const STACK_COUNT: usize>(input: &str) -> Supplies<STACK_COUNT> {
+        Supplies::parse(input).unwrap()
+    }
+
+    #[test]
+    fn day5_part1_ex() {
+        assert_eq!(part1_solve(supplies::<3>(EX)).unwrap(), "CMZ");
+    }
+    #[test]
+    fn day5_part1_real() {
+        assert_eq!(part1_solve(supplies::<9>(REAL)).unwrap(), "LBLVVTVLP");
+    }
+    #[test]
+    fn day5_part2_ex() {
+        assert_eq!(
+            part2_solve(supplies::<3>(EX), &mut vis::Off).unwrap(),
+            "MCD"
+        );
+    }
+    #[test]
+    fn day5_part2_real() {
+        assert_eq!(
+            part2_solve(supplies::<9>(REAL), &mut vis::Off).unwrap(),
+            "TPFFBDRJD"
+        );
+    }
+    #[test]
+    fn day5_bad_input() {
+        assert_eq!(
+            parse("garbage".to_string()).unwrap_err().to_string(),
+            "parse error at line 1, column 1: expected the stacks, a blank line, then the moves"
+        );
+        let bad_move = EX.replace("move 3 from 1 to 3", "move 3 from 1 to 4");
+        assert_eq!(
+            Supplies::<3>::parse(&bad_move).unwrap_err().to_string(),
+            "parse error at line 7, column 1: expected a move like `move 1 from 2 to 1`, between stacks 1 to 3"
+        );
+        let too_many = EX.replace("move 2 from 2 to 1", "move 9 from 2 to 1");
+        assert_eq!(
+            part1_solve(supplies::<3>(&too_many)),
+            Err(AocError::NoSolution("stack 2 ran out of crates".into()))
+        );
+    }
+    #[test]
+    fn day5_part2_visualized() {
+        let mut rec = vis::Recorder::new(12, TERM_HEIGHT as u32, 60);
+        assert_eq!(part2_solve(supplies::<3>(EX), &mut rec).unwrap(), "MCD");
+
+        // one frame per crate moved
+        assert_eq!(rec.frames.len(), 7);
+        let last = rec.frames.last().unwrap().to_string();
+        assert!(
+            last.ends_with("        [D]\n        [N]\n        [Z]\n[M] [C] [P]\n 1   2   3\n\n")
+        );
+        assert_eq!(last.lines().count(), TERM_HEIGHT as usize);
+        assert_eq!(
+            rec.frames[6].duration,
+            Duration::from_secs(1) / 60 + Duration::from_secs(2)
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{AocError, Result, Solution};
+
+type Parsed = String;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    let line = input.trim_end();
+    if let Some(col) = line.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(AocError::parse_at(
+            line,
+            &line[col..],
+            "expected only lowercase letters",
+        ));
+    }
+    Ok(line.to_string())
+}
+
+pub fn part1(input: Parsed) -> Result<usize> {
+    find_marker::<4>(&input)
+}
+
+pub fn part2(input: Parsed) -> Result<usize> {
+    find_marker::<14>(&input)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part1(model)
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
+}
+
+/// The line must be lowercase letters only, as [`parse`] makes sure.
+fn find_marker<const MARKER_LEN: usize>(line: &str) -> Result<usize> {
+    let chars: Vec<char> = line.chars().collect();
+
+    let mut letters_seen: [bool; 26] = [false; 26];
+
+    'win: for (i, win) in chars.windows(MARKER_LEN).enumerate() {
+        // clear the letters seen
+        letters_seen.iter_mut().for_each(|l| *l = false);
+
+        for c in win {
+            let ci = *c as usize - 97;
+            if letters_seen[ci] {
+                continue 'win;
+            }
+            letters_seen[ci] = true;
+        }
+
+        return Ok(i + MARKER_LEN);
+    }
+
+    Err(AocError::NoSolution(format!(
+        "no {MARKER_LEN} distinct characters in a row"
+    )))
+}
+
+#[cfg(test)]
+mod day6_tests {
+    use super::*;
+
+    #[test]
+    fn part1_parse_test() {
+        assert_eq!(find_marker::<4>("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Ok(7));
+        assert_eq!(find_marker::<4>("bvwbjplbgvbhsrlpgdmjqwftvncz"), Ok(5));
+        assert_eq!(find_marker::<4>("nppdvjthqldpwncqszvftbrmjlhg"), Ok(6));
+        assert_eq!(
+            find_marker::<4>("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
+            Ok(10)
+        );
+        assert_eq!(find_marker::<4>("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Ok(11));
+    }
+
+    #[test]
+    fn bad_input() {
+        assert!(matches!(
+            find_marker::<4>("abcabc"),
+            Err(AocError::NoSolution(_))
+        ));
+        assert!(matches!(
+            parse("abcD".to_string()),
+            Err(AocError::Parse { column: 4, .. })
+        ));
+    }
+}
@@ -1,32 +1,57 @@
 mod parse;
 
+use crate::{AocError, Result, Solution};
 use nom::{
     bytes::complete::tag,
     character::complete::{newline, not_line_ending, space1, u32 as nom_u32},
-    combinator::map,
+    combinator::{all_consuming, map},
     sequence::tuple,
     IResult,
 };
 
-type Parsed = String;
+type Parsed<'a> = Vec<Log<'a>>;
 
-pub fn parse(input: String) -> Parsed {
-    input
+pub fn parse(input: &str) -> Result<Parsed<'_>> {
+    let input = input.trim_end();
+    all_consuming(parse::log)(input)
+        .map(|(_, entries)| entries)
+        .map_err(AocError::from_nom(
+            input,
+            "expected `$ cd`, `$ ls`, `dir` or a file",
+        ))
 }
 
-pub fn part1(input: Parsed) -> u32 {
-    let (_, entries) = parse::log(input.as_str()).expect("could not parse input");
+pub fn part1(entries: Parsed) -> u32 {
     let fs = Filesystem::new(entries);
 
     fs.sum_under(100000)
 }
 
-pub fn part2(input: Parsed) -> u32 {
-    let (_, entries) = parse::log(input.as_str()).expect("could not parse input");
+pub fn part2(entries: Parsed) -> Result<u32> {
     let fs = Filesystem::new(entries);
 
     fs.free_up(70_000_000, 30_000_000)
-        .expect("no dir found that can free up enough space")
+        .ok_or_else(|| AocError::NoSolution("no dir found that can free up enough space".into()))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Model<'input> = Parsed<'input>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input)
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        part2(model)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -197,25 +222,25 @@ $ ls
 8033020 d.log
 5626152 d.ext
 7214296 k";
-    const REAL: &str = include_str!("../input/d7");
+    const REAL: &str = include_str!("../../input/2022/d7");
 
     #[test]
     fn day7_part1_example() {
-        assert_eq!(part1(parse(EX.into())), 95437);
+        assert_eq!(part1(parse(EX).unwrap()), 95437);
     }
 
     #[test]
     fn day7_part1_real() {
-        assert_eq!(part1(parse(REAL.into())), 1232307);
+        assert_eq!(part1(parse(REAL).unwrap()), 1232307);
     }
 
     #[test]
     fn day7_part2_example() {
-        assert_eq!(part2(parse(EX.into())), 24933642);
+        assert_eq!(part2(parse(EX).unwrap()).unwrap(), 24933642);
     }
 
     #[test]
     fn day7_part2_real() {
-        assert_eq!(part2(parse(REAL.into())), 7268994);
+        assert_eq!(part2(parse(REAL).unwrap()).unwrap(), 7268994);
     }
 }
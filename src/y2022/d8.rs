@@ -0,0 +1,123 @@
+use crate::{
+    geom::{Dir, Point},
+    grid::Grid,
+    AocError, Result, Solution,
+};
+
+type Parsed = Grid<u8>;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    let heights = Grid::parse_with(&input, |c| c.to_digit(10).map(|d| d as u8));
+    // short lines are padded out with spaces, so they show up here too
+    if let Some(p) = heights.position(Option::is_none) {
+        return Err(AocError::Parse {
+            line: p.y + 1,
+            column: p.x + 1,
+            msg: "expected a rectangle of tree heights from 0 to 9".into(),
+        });
+    }
+    Ok(heights.map(|h| h.unwrap_or_default()))
+}
+
+pub fn part1(trees: Parsed) -> usize {
+    Forest { trees }.count_visible()
+}
+
+pub fn part2(trees: Parsed) -> usize {
+    Forest { trees }.max_scenic()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
+struct Forest {
+    trees: Grid<u8>,
+}
+
+impl Forest {
+    /// Trees with nothing as tall between them and at least one edge.
+    fn count_visible(&self) -> usize {
+        self.trees
+            .iter()
+            .filter(|&(p, height)| {
+                Dir::ALL
+                    .into_iter()
+                    .any(|dir| self.trees.ray(p, dir).all(|q| self.trees[q] < *height))
+            })
+            .count()
+    }
+
+    fn max_scenic(&self) -> usize {
+        self.trees
+            .points()
+            .map(|p| self.scenic_score(p))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn scenic_score(&self, p: Point<usize>) -> usize {
+        let height = self.trees[p];
+        Dir::ALL
+            .into_iter()
+            .map(|dir| {
+                let mut seen = 0;
+                for q in self.trees.ray(p, dir) {
+                    seen += 1;
+                    if self.trees[q] >= height {
+                        break;
+                    }
+                }
+                seen
+            })
+            .product()
+    }
+}
+
+#[test]
+fn day8_test() {
+    const EX: &str = "30373\n\
+                      25512\n\
+                      65332\n\
+                      33549\n\
+                      35390";
+
+    let trees = parse(EX.to_string()).unwrap();
+    assert_eq!((trees.width(), trees.height()), (5, 5));
+    assert_eq!(
+        trees.render(|&height| char::from(b'0' + height)),
+        "30373\n25512\n65332\n33549\n35390\n"
+    );
+    assert_eq!(
+        Forest {
+            trees: trees.clone()
+        }
+        .count_visible(),
+        21
+    );
+    assert_eq!(Forest { trees }.max_scenic(), 8);
+}
+
+#[test]
+fn day8_bad_input() {
+    assert_eq!(
+        parse("303\n25\n653".to_string()).unwrap_err().to_string(),
+        "parse error at line 2, column 3: expected a rectangle of tree heights from 0 to 9"
+    );
+}
@@ -0,0 +1,258 @@
+use crate::{
+    geom::{Bounds, Dir, Point},
+    sparse::SparseGrid,
+    vis::{self, svg::Svg, Rgb},
+    AocError, Result, Solution,
+};
+
+/// Which way the head moves, and how far.
+type Parsed = Vec<(Dir, i32)>;
+
+pub fn parse(input: String) -> Result<Parsed> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let motion = line.split_once(' ').and_then(|(dir, amt)| {
+                let dir = match dir {
+                    "U" => Dir::Up,
+                    "D" => Dir::Down,
+                    "R" => Dir::Right,
+                    "L" => Dir::Left,
+                    _ => return None,
+                };
+                Some((dir, amt.parse::<i32>().ok()?))
+            });
+            motion.ok_or_else(|| AocError::parse_line(i, "expected a motion like `R 4`"))
+        })
+        .collect()
+}
+
+pub fn part1(motions: Parsed) -> usize {
+    let mut hx = 0;
+    let mut hy = 0;
+    let mut tx = 0;
+    let mut ty = 0;
+
+    let mut visited = SparseGrid::new();
+
+    // +y is up
+
+    for &(dir, amt) in &motions {
+        for _ in 0..amt {
+            match dir {
+                Dir::Up => hy += 1,
+                Dir::Down => hy -= 1,
+                Dir::Right => hx += 1,
+                Dir::Left => hx -= 1,
+            }
+
+            match (hx - tx, hy - ty) {
+                (2, _) => {
+                    // H on right, moving right
+                    tx += 1;
+                    ty = hy;
+                }
+                (-2, _) => {
+                    // H on right, moving right
+                    tx -= 1;
+                    ty = hy;
+                }
+                (_, 2) => {
+                    // H above, moving up
+                    ty += 1;
+                    tx = hx;
+                }
+                (_, -2) => {
+                    ty -= 1;
+                    tx = hx;
+                }
+                _ => {}
+            }
+
+            visited.insert(Point::new(tx, ty), ());
+        }
+    }
+
+    vis::snapshot(|| trail_svg(&visited, &[(hx, hy), (tx, ty)]));
+
+    visited.len()
+}
+
+pub fn part2(motions: Parsed) -> usize {
+    let mut segs: [(i32, i32); 10] = [(0, 0); 10];
+
+    let mut visited = SparseGrid::new();
+
+    // +y is up
+
+    for &(dir, amt) in &motions {
+        for _ in 0..amt {
+            {
+                let head = segs.first_mut().unwrap();
+                match dir {
+                    Dir::Up => head.1 += 1,
+                    Dir::Down => head.1 -= 1,
+                    Dir::Right => head.0 += 1,
+                    Dir::Left => head.0 -= 1,
+                }
+            }
+
+            for i in 0..(segs.len() - 1) {
+                let head = *segs.get(i).unwrap();
+                let tail = segs.get_mut(i + 1).unwrap();
+
+                match (head.0 - tail.0, head.1 - tail.1) {
+                    (2, 2) => {
+                        tail.0 += 1;
+                        tail.1 += 1;
+                    }
+                    (-2, -2) => {
+                        tail.0 -= 1;
+                        tail.1 -= 1;
+                    }
+                    (2, -2) => {
+                        tail.0 += 1;
+                        tail.1 -= 1;
+                    }
+                    (-2, 2) => {
+                        tail.0 -= 1;
+                        tail.1 += 1;
+                    }
+                    (2, _) => {
+                        // H on right, moving right
+                        tail.0 += 1;
+                        tail.1 = head.1;
+                    }
+                    (-2, _) => {
+                        // H on right, moving right
+                        tail.0 -= 1;
+                        tail.1 = head.1;
+                    }
+                    (_, 2) => {
+                        // H above, moving up
+                        tail.1 += 1;
+                        tail.0 = head.0;
+                    }
+                    (_, -2) => {
+                        tail.1 -= 1;
+                        tail.0 = head.0;
+                    }
+                    _ => {}
+                }
+            }
+
+            let (tx, ty) = segs[segs.len() - 1];
+            visited.insert(Point::new(tx, ty), ());
+        }
+    }
+
+    vis::snapshot(|| trail_svg(&visited, &segs));
+
+    visited.len()
+}
+
+/// Every spot the tail visited, with the rope's final position drawn over them, head first.
+fn trail_svg(visited: &SparseGrid<()>, rope: &[(i32, i32)]) -> Svg {
+    // +y is up here, but down in an SVG
+    let flip = |&(x, y): &(i32, i32)| (x, -y);
+
+    // the rope starts at 0,0
+    let mut bounds = Bounds::new(Point::new(0, 0));
+    if let Some(trail) = visited.bounds() {
+        bounds.include(trail.min);
+        bounds.include(trail.max);
+    }
+    for &(x, y) in rope {
+        bounds.include(Point::new(x, y));
+    }
+
+    let mut svg = Svg::around((bounds.min.x, -bounds.max.y), (bounds.max.x, -bounds.min.y));
+    svg.background(Rgb(20, 20, 28));
+    for p in visited.points() {
+        svg.cell(p.x, -p.y, Rgb(36, 114, 200));
+    }
+    svg.circle(0.5, 0.5, 0.3, Rgb(128, 128, 128));
+    let knots: Vec<(f64, f64)> = rope
+        .iter()
+        .map(flip)
+        .map(|(x, y)| (x as f64 + 0.5, y as f64 + 0.5))
+        .collect();
+    svg.polyline(&knots, Rgb(229, 200, 110), 0.3);
+    if let Some(&(x, y)) = knots.first() {
+        svg.circle(x, y, 0.4, Rgb(205, 49, 49));
+    }
+    svg
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Model<'input> = Parsed;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
+#[test]
+fn day9_part1_test() {
+    let ex = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    assert_eq!(part1(parse(ex.to_string()).unwrap()), 13);
+}
+
+#[test]
+fn day9_part2_test() {
+    let ex = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    assert_eq!(part2(parse(ex.to_string()).unwrap()), 36);
+}
+
+#[test]
+fn day9_bad_input() {
+    assert_eq!(
+        parse("R 4\nU four".to_string()).unwrap_err().to_string(),
+        "parse error at line 2, column 1: expected a motion like `R 4`"
+    );
+}
+
+#[test]
+fn day9_trail_svg() {
+    let visited = [(0, 0), (1, 0), (2, 1)]
+        .map(|(x, y)| (Point::new(x, y), ()))
+        .into_iter()
+        .collect();
+    let svg = trail_svg(&visited, &[(3, 2), (2, 1)]).to_string();
+
+    // flipped, the spots run from 0,-2 to 3,0
+    assert!(svg.contains("viewBox=\"-1 -3 6 5\""));
+    assert_eq!(svg.matches("fill=\"#2472c8\"").count(), 3);
+    assert!(svg.contains("<rect x=\"2\" y=\"-1\" width=\"1\" height=\"1\" fill=\"#2472c8\"/>"));
+    assert!(svg.contains("points=\"3.5,-1.5 2.5,-0.5\""));
+    assert!(svg.contains("<circle cx=\"3.5\" cy=\"-1.5\" r=\"0.4\""));
+}
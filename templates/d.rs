@@ -1,11 +1,13 @@
 //! A solution to day $AOC_DAY year $AOC_YEAR.
 //! https://adventofcode.com/$AOC_YEAR/day/$AOC_DAY
 
+use crate::{Result, Solution};
+
 type Model = u8;
 type Answer = String;
 
-pub fn parse(input: String) -> Model {
-    0
+pub fn parse(input: String) -> Result<Model> {
+    Ok(0)
 }
 
 pub fn part1(input: Model) -> Answer {
@@ -16,17 +18,37 @@ pub fn part2(input: Model) -> Answer {
     "incomplete".to_string()
 }
 
+pub struct Day$AOC_DAY;
+
+impl Solution for Day$AOC_DAY {
+    const DAY: u8 = $AOC_DAY;
+    type Model<'input> = Model;
+    type Answer = Answer;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>> {
+        parse(input.to_string())
+    }
+
+    fn part1(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part1(model))
+    }
+
+    fn part2(model: Self::Model<'_>) -> Result<Self::Answer> {
+        Ok(part2(model))
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
 //
-//     const INPUT: &str = include_str!("../input/d$AOC_DAY");
-//     const EXAMPLE: &str = include_str!("../examples/d$AOC_DAY");
+//     const INPUT: &str = include_str!("../../input/$AOC_YEAR/d$AOC_DAY");
+//     const EXAMPLE: &str = include_str!("../../examples/$AOC_YEAR/d$AOC_DAY");
 //
 //     // #[test]
 //     // fn d${AOC_DAY}p1_example_test() {
 //     //     assert_eq!(
-//     //         part1(parse(EXAMPLE.to_string())),
+//     //         part1(parse(EXAMPLE.to_string()).unwrap()),
 //     //         "put part 1 example answer here"
 //     //     );
 //     // }
@@ -34,7 +56,7 @@ pub fn part2(input: Model) -> Answer {
 //     // #[test]
 //     // fn d${AOC_DAY}p1_input_test() {
 //     //     assert_eq!(
-//     //         part1(parse(INPUT.to_string())),
+//     //         part1(parse(INPUT.to_string()).unwrap()),
 //     //         "put part 1 final answer here"
 //     //     );
 //     // }
@@ -42,7 +64,7 @@ pub fn part2(input: Model) -> Answer {
 //     // #[test]
 //     // fn d${AOC_DAY}p2_example_test() {
 //     //     assert_eq!(
-//     //         part2(parse(EXAMPLE.to_string())),
+//     //         part2(parse(EXAMPLE.to_string()).unwrap()),
 //     //         "put part 2 example answer here"
 //     //     );
 //     // }
@@ -50,7 +72,7 @@ pub fn part2(input: Model) -> Answer {
 //     // #[test]
 //     // fn d${AOC_DAY}p2_input_test() {
 //     //     assert_eq!(
-//     //         part2(parse(INPUT.to_string())),
+//     //         part2(parse(INPUT.to_string()).unwrap()),
 //     //         "put part 2 final answer here"
 //     //     );
 //     // }